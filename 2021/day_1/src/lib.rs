@@ -0,0 +1,58 @@
+use aoc_core::{parse, Answer, Params, ParseError, Solution};
+
+fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    // Read input line by line
+    return input.lines().map(parse::number::<i32>).collect();
+}
+
+fn solve_problem_1(depths: &[i32]) -> Answer {
+    let mut ans = 0;
+    let mut previous_depth: i32 = -1;
+
+    for &current_depth in depths.iter() {
+        if previous_depth != -1 && previous_depth < current_depth {
+            ans += 1;
+        }
+        previous_depth = current_depth;
+    }
+
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(depths: &[i32]) -> Answer {
+    let mut ans = 0;
+    let mut sliding_window: [i32; 3] = [0; 3];
+
+    for (index, &current_depth) in depths.iter().enumerate() {
+        if index <= 2 {
+            sliding_window[index] = current_depth;
+        } else {
+            let previous_window_sum = sliding_window.iter().sum::<i32>();
+            sliding_window[index % 3] = current_depth;
+            let current_window_sum = sliding_window.iter().sum::<i32>();
+            if current_window_sum > previous_window_sum {
+                ans += 1;
+            }
+        }
+    }
+
+    return Answer::from(ans);
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
+    }
+}
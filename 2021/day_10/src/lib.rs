@@ -1,26 +1,7 @@
-use std::fs::{File};
-use std::io::{BufReader, BufRead, Lines};
-use clap::{Parser};
-
-#[derive(Debug, Parser)]
-struct Arg {
-    /// Challenge to run (1 or 2)
-    challenge_num: u8,
-
-    /// Input file
-    filename: String,
-}
-
-fn read_lines(filename: &str) -> Lines<BufReader<File>> {
-    let file = match File::open(filename) {
-        Ok(f) => { f },
-        Err(e) => { panic!("[ERROR] {}", e) }
-    };
-    BufReader::new(file).lines()
-}
+use aoc_core::{Answer, Params, ParseError, Solution};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
-enum Opening {
+pub enum Opening {
     Parenthesis,
     Bracket,
     CurlyBracket,
@@ -49,7 +30,7 @@ impl Opening {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
-enum Closing {
+pub enum Closing {
     Parenthesis,
     Bracket,
     CurlyBracket,
@@ -86,18 +67,43 @@ impl Closing {
     }
 }
 
-fn solve_problem_1(filename: &str) {
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Delimiter {
+    Open(Opening),
+    Close(Closing),
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<Delimiter>>, ParseError> {
+    let mut lines = Vec::new();
+
+    // Read input line by line
+    for s in input.lines() {
+        let mut delimiters = Vec::new();
+        for (index, character) in s.char_indices() {
+            if let Ok(opening) = Opening::from_char(character) {
+                delimiters.push(Delimiter::Open(opening));
+            } else if let Ok(closing) = Closing::from_char(character) {
+                delimiters.push(Delimiter::Close(closing));
+            } else {
+                let part = &s[index..index + character.len_utf8()];
+                return Err(ParseError::new(part, format!("'{}' is not a valid delimiter", character)));
+            }
+        }
+        lines.push(delimiters);
+    }
+
+    return Ok(lines);
+}
+
+fn solve_problem_1(lines: &[Vec<Delimiter>]) -> Answer {
     let mut ans = 0;
 
-    // Open file and read line by line
-    let lines = read_lines(filename);
-    for line in lines {
-        if let Ok(s) = line {
-            let mut stack: Vec<Opening> = Vec::new();
-            for character in s.chars() {
-                if let Ok(opening) = Opening::from_char(character) {
-                    stack.push(opening);
-                } else if let Ok(closing) = Closing::from_char(character) {
+    for delimiters in lines.iter() {
+        let mut stack: Vec<Opening> = Vec::new();
+        for &delimiter in delimiters.iter() {
+            match delimiter {
+                Delimiter::Open(opening) => { stack.push(opening); },
+                Delimiter::Close(closing) => {
                     match stack.pop() {
                         Some(opening) => {
                             if opening.get_closing() != closing {
@@ -106,28 +112,23 @@ fn solve_problem_1(filename: &str) {
                         }
                         None => { ans += closing.get_corruption_score(); }
                     };
-                } else {
-                    panic!("[Error] '{}' is not a valid delimiter!", character);
-                }
+                },
             }
         }
     }
 
-    println!("Answer: {:?}", ans);
+    return Answer::from(ans);
 }
 
-fn solve_problem_2(filename: &str) {
+fn solve_problem_2(lines: &[Vec<Delimiter>]) -> Answer {
     let mut ans_array: Vec<u64> = Vec::new();
 
-    // Open file and read line by line
-    let lines = read_lines(filename);
-    for line in lines {
-        if let Ok(s) = line {
-            let mut stack: Vec<Opening> = Vec::new();
-            for character in s.chars() {
-                if let Ok(opening) = Opening::from_char(character) {
-                    stack.push(opening);
-                } else if let Ok(closing) = Closing::from_char(character) {
+    for delimiters in lines.iter() {
+        let mut stack: Vec<Opening> = Vec::new();
+        for &delimiter in delimiters.iter() {
+            match delimiter {
+                Delimiter::Open(opening) => { stack.push(opening); },
+                Delimiter::Close(closing) => {
                     match stack.pop() {
                         Some(opening) => {
                             if opening.get_closing() != closing {
@@ -140,32 +141,38 @@ fn solve_problem_2(filename: &str) {
                             break;
                         }
                     };
-                } else {
-                    panic!("[Error] '{}' is not a valid delimiter!", character);
-                }
-            }
-            let mut score: u64 = 0;
-            while let Some(opening) = stack.pop() {
-                score *= 5;
-                score += opening.get_closing().get_autocompletion_score();
-            }
-            if score != 0 {
-                ans_array.push(score);
+                },
             }
         }
+        let mut score: u64 = 0;
+        while let Some(opening) = stack.pop() {
+            score *= 5;
+            score += opening.get_closing().get_autocompletion_score();
+        }
+        if score != 0 {
+            ans_array.push(score);
+        }
     }
 
     ans_array.sort_unstable();
     let ans = ans_array[ans_array.len()/2];
-    println!("Answer: {:?}", ans);
+    return Answer::from(ans);
 }
 
-fn main() {
-    let arg = Arg::parse();
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Vec<Delimiter>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
 
-    match arg.challenge_num {
-        1 => { solve_problem_1(&arg.filename); },
-        2 => { solve_problem_2(&arg.filename); },
-        n => { panic!("[ERROR] Incorrect challenge number {}", n); }
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
     }
 }
@@ -1 +0,0 @@
-../day_template/run.sh
\ No newline at end of file
@@ -0,0 +1,108 @@
+use aoc_core::grid::{self, Pos};
+use aoc_core::visualize::{self, Cell, Color};
+use aoc_core::{Answer, Frame, Grid, Params, ParseError, Solution};
+
+const FLASHED_THIS_STEP: u8 = 200;
+const ENERGY_TO_FLASH: u8 = 10;
+
+/// Energy levels of the octopuses, the ones that just flashed (0) standing out
+fn draw_map(map: &Grid<u8>, step: u64) -> Frame {
+    let frame = Frame::from_grid(map, |&energy| {
+        let digit = char::from_digit(energy as u32, 36).unwrap_or('*');
+        if energy == 0 { Cell::colored(digit, Color::Yellow) } else { Cell::colored(digit, Color::Gray) }
+    });
+    return frame.titled(format!("After step {}", step));
+}
+
+fn parse_octopuses(input: &str) -> Result<Grid<u8>, ParseError> {
+    return Grid::parse(input, |c| grid::digit(c).map(|digit| digit as u8));
+}
+
+fn flash_and_propagate(matrix: &mut Grid<u8>, pos: Pos) -> u64 {
+    if matrix[pos] == FLASHED_THIS_STEP || matrix[pos] < ENERGY_TO_FLASH {
+        return 0;
+    }
+    matrix[pos] = FLASHED_THIS_STEP;
+    let mut nb_flashes = 1;
+    for neighbor in matrix.neighbors8(pos) {
+        matrix[neighbor] += if matrix[neighbor] == FLASHED_THIS_STEP { 0 } else { 1 } ;
+        nb_flashes += flash_and_propagate(matrix, neighbor);
+    }
+    return nb_flashes;
+}
+
+fn simulate_step(matrix: &mut Grid<u8>) -> u64 {
+    // Increase energy levels by 1
+    for (_, octopus) in matrix.iter_mut() {
+        *octopus += 1;
+    }
+
+    let mut nb_flashes = 0;
+    for pos in matrix.positions() {
+        nb_flashes += flash_and_propagate(matrix, pos);
+    }
+
+    // Reset flashed octopuses
+    for (_, octopus) in matrix.iter_mut() {
+        if *octopus == FLASHED_THIS_STEP {
+            *octopus = 0;
+        }
+    }
+
+    return nb_flashes;
+}
+
+fn simulate_steps(matrix: &mut Grid<u8>, n: Option<usize>) -> u64 {
+    visualize::frame(|| draw_map(matrix, 0));
+    let mut nb_flashes = 0;
+    match n {
+        Some(n) => {
+            for i in 1..=n {
+                nb_flashes += simulate_step(matrix);
+                visualize::frame(|| draw_map(matrix, i as u64));
+            }
+        }
+        None => {
+            let mut i = 0;
+            loop {
+                i += 1;
+                let step_flashes = simulate_step(matrix);
+                visualize::frame(|| draw_map(matrix, i));
+                if step_flashes == matrix.len() as u64 {
+                    return i;
+                }
+            }
+        }
+    }
+    return nb_flashes;
+}
+
+fn solve_problem_1(matrix: &Grid<u8>) -> Answer {
+    let mut matrix = matrix.clone();
+    let ans = simulate_steps(&mut matrix, Some(100));
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(matrix: &Grid<u8>) -> Answer {
+    let mut matrix = matrix.clone();
+    let ans = simulate_steps(&mut matrix, None);
+    return Answer::from(ans);
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<u8>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_octopuses(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
+    }
+}
@@ -0,0 +1,119 @@
+use aoc_core::{parse, Answer, Params, ParseError, Solution};
+use std::str::FromStr;
+
+#[derive(Clone, Copy)]
+pub enum Command {
+    Up(i32),
+    Down(i32),
+    Forward(i32),
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, amount) = parse::split_once(s, " ")?;
+        let amount = parse::number::<i32>(amount)?;
+
+        match direction {
+            "up" => { Ok(Self::Up(amount)) },
+            "down" => { Ok(Self::Down(amount)) },
+            "forward" => { Ok(Self::Forward(amount)) },
+            _ => { Err(ParseError::new(direction, format!("Incorrect command: {}", direction))) }
+        }
+    }
+}
+
+struct Submarine {
+    // Horizontal position
+    x: i32,
+    // Vertical position
+    y: i32,
+    // Aim
+    aim: i32,
+}
+
+impl Submarine {
+    fn new() -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            aim: 0,
+        }
+    }
+
+    fn execute_command(&mut self, command: Command) {
+        match command {
+            Command::Up(amount) => {
+                self.y -= amount;
+            },
+            Command::Down(amount) => {
+                self.y += amount;
+            },
+            Command::Forward(amount) => {
+                self.x += amount;
+            },
+        }
+    }
+
+    fn execute_command_with_aim(&mut self, command: Command) {
+        match command {
+            Command::Up(amount) => {
+                self.aim -= amount;
+            },
+            Command::Down(amount) => {
+                self.aim += amount;
+            },
+            Command::Forward(amount) => {
+                self.x += amount;
+                self.y += self.aim * amount;
+            },
+        }
+    }
+
+}
+
+fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    // Read input line by line
+    return input.lines().map(Command::from_str).collect();
+}
+
+fn solve_problem_1(commands: &[Command]) -> Answer {
+    let mut submarine = Submarine::new();
+
+    for &command in commands.iter() {
+        submarine.execute_command(command);
+    }
+
+    let ans = submarine.x * submarine.y;
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(commands: &[Command]) -> Answer {
+    let mut submarine = Submarine::new();
+
+    for &command in commands.iter() {
+        submarine.execute_command_with_aim(command);
+    }
+
+    let ans = submarine.x * submarine.y;
+    return Answer::from(ans);
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Command>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
+    }
+}
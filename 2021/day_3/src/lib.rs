@@ -1,29 +1,10 @@
-use std::fs::{File};
-use std::io::{BufReader, BufRead, Lines};
-use clap::{Parser};
-
-#[derive(Debug, Parser)]
-struct Arg {
-    /// Challenge to run (1 or 2)
-    challenge_num: u8,
-
-    /// Input file
-    filename: String,
-}
+use aoc_core::{Answer, Params, ParseError, Solution};
 
 enum Mode {
     OxygenGeneratorRateMode,
     Co2ScrubberRateMode,
 }
 
-fn read_lines(filename: &str) -> Lines<BufReader<File>> {
-    let file = match File::open(filename) {
-        Ok(f) => { f },
-        Err(e) => { panic!("[ERROR] {}", e) }
-    };
-    BufReader::new(file).lines()
-}
-
 fn i32_from_bit_string(s: &str) -> i32 {
     let mut res = 0;
     for bit in s.chars() {
@@ -42,14 +23,14 @@ fn get_rate(mut possible_values: Vec<String>, mode: Mode) -> i32{
         let ones: Vec<String> = possible_values.iter()
             .filter(
                 |s| s.chars().nth(index) == Some('1')
-            ).map(|s|String::from(s))
+            ).map(String::from)
             .collect();
 
         // Filter zeros at position "index"
         let zeros: Vec<String> = possible_values.iter()
             .filter(
                 |s| s.chars().nth(index) == Some('0')
-            ).map(|s|String::from(s))
+            ).map(String::from)
             .collect();
 
         // Find the most common bit
@@ -70,23 +51,33 @@ fn get_rate(mut possible_values: Vec<String>, mode: Mode) -> i32{
     i32_from_bit_string(&possible_values[0])
 }
 
-fn solve_problem_1(filename: &str) {
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut values = Vec::new();
+
+    // Read input line by line
+    for s in input.lines() {
+        if let Some((index, c)) = s.char_indices().find(|&(_, c)| c != '0' && c != '1') {
+            return Err(ParseError::new(&s[index..index + c.len_utf8()], "Expected a binary digit"));
+        }
+        values.push(String::from(s));
+    }
+
+    return Ok(values);
+}
+
+fn solve_problem_1(values: &[String]) -> Answer {
     // Counts the number of 1s at each position
     let mut counter = Vec::new();
 
-    // Open file and read line by line
-    let lines = read_lines(filename);
-    for line in lines {
-        if let Ok(s) = line {
-            for (index, bit) in s.chars().enumerate() {
-                if index >= counter.len() {
-                    counter.push(0);
-                }
-                if bit == '1' {
-                    counter[index] += 1;
-                } else if bit == '0' {
-                    counter[index] -= 1;
-                }
+    for s in values.iter() {
+        for (index, bit) in s.chars().enumerate() {
+            if index >= counter.len() {
+                counter.push(0);
+            }
+            if bit == '1' {
+                counter[index] += 1;
+            } else if bit == '0' {
+                counter[index] -= 1;
             }
         }
     }
@@ -104,32 +95,31 @@ fn solve_problem_1(filename: &str) {
     }
 
     let ans = gamma_rate * epsilon_rate;
-    println!("Answer: {:?}", ans);
+    return Answer::from(ans);
 }
 
-fn solve_problem_2(filename: &str) {
-    let mut possible_values = Vec::new();
-    // Open file and read line by line
-    let lines = read_lines(filename);
-    for line in lines {
-        if let Ok(s) = line {
-            possible_values.push(s);
-        }
-    }
-
-    let oxygen_rate = get_rate(possible_values.clone(), Mode::OxygenGeneratorRateMode);
-    let co2_rate = get_rate(possible_values, Mode::Co2ScrubberRateMode);
+fn solve_problem_2(values: &[String]) -> Answer {
+    let oxygen_rate = get_rate(values.to_vec(), Mode::OxygenGeneratorRateMode);
+    let co2_rate = get_rate(values.to_vec(), Mode::Co2ScrubberRateMode);
 
     let ans = oxygen_rate * co2_rate;
-    println!("Answer: {:?}", ans);
+    return Answer::from(ans);
 }
 
-fn main() {
-    let arg = Arg::parse();
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
 
-    match arg.challenge_num {
-        1 => { solve_problem_1(&arg.filename); },
-        2 => { solve_problem_2(&arg.filename); },
-        n => { panic!("[ERROR] Incorrect challenge number {}", n); }
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
     }
 }
@@ -1,15 +1,4 @@
-use std::fs::{File};
-use std::io::{BufReader, BufRead, Lines};
-use clap::{Parser};
-
-#[derive(Debug, Parser)]
-struct Arg {
-    /// Challenge to run (1 or 2)
-    challenge_num: u8,
-
-    /// Input file
-    filename: String,
-}
+use aoc_core::{input, parse, Answer, Params, ParseError, Solution};
 
 #[derive(Debug, Copy, Clone)]
 struct BingoTile {
@@ -18,7 +7,7 @@ struct BingoTile {
 }
 
 #[derive(Debug, Clone)]
-struct BingoGrid {
+pub struct BingoGrid {
     tiles: [[BingoTile; 5]; 5],
 }
 
@@ -33,20 +22,17 @@ impl BingoGrid {
         }
     }
 
-    fn set_row(&mut self, row_index: usize, s: &str) {
-        let mut col_index = 0;
-        for num_str in s.split(" ") {
-            if num_str.is_empty() {
-                continue;
-            }
-            let value = match num_str.parse::<i32>() {
-                Ok(num) => { num },
-                Err(e) => { panic!("{}", e); }
-            };
+    fn set_row(&mut self, row_index: usize, s: &str) -> Result<(), ParseError> {
+        // Numbers are right aligned, separated by one or two spaces
+        let values = parse::numbers::<i32>(s, " ")?;
+        if values.len() != 5 {
+            return Err(ParseError::new(s, "Expected 5 numbers per row"));
+        }
+        for (col_index, value) in values.into_iter().enumerate() {
             self.tiles[row_index][col_index].number = value;
             self.tiles[row_index][col_index].drawn = false;
-            col_index += 1;
         }
+        return Ok(());
     }
 
     fn wins(&self) -> bool {
@@ -90,47 +76,34 @@ impl BingoGrid {
     }
 }
 
-fn read_lines(filename: &str) -> Lines<BufReader<File>> {
-    let file = match File::open(filename) {
-        Ok(f) => { f },
-        Err(e) => { panic!("[ERROR] {}", e) }
-    };
-    BufReader::new(file).lines()
-}
+fn parse_file(input: &str) -> Result<(Vec<i32>, Vec<BingoGrid>), ParseError> {
+    let blocks = input::paragraphs(input);
 
-fn parse_file(filename: &str) -> (String, Vec<BingoGrid>) {
-    let mut draw_order = String::from("");
+    // The first block is the draw order, each following block is a grid
+    let draw_order = match blocks.first() {
+        Some(s) => { parse::numbers::<i32>(s, ",")? },
+        None => { return Err(ParseError::new(input, "Empty input")); }
+    };
     let mut grids = Vec::new();
-    let mut row_number = 0;
-    let mut current_grid = BingoGrid::new();
-
-
-    let lines = read_lines(filename);
-    for line in lines {
-        if let Ok(s) = line {
-            if draw_order == "" {
-                draw_order = s;
-            } else if s.is_empty() {
-                grids.push(current_grid.clone());
-                row_number = 0;
-            } else {
-                current_grid.set_row(row_number, &s);
-                row_number += 1;
-            }
+    for block in blocks.iter().skip(1) {
+        if block.lines().count() != 5 {
+            return Err(ParseError::new(block, "Expected 5 rows per grid"));
+        }
+        let mut grid = BingoGrid::new();
+        for (row_number, s) in block.lines().enumerate() {
+            grid.set_row(row_number, s)?;
         }
+        grids.push(grid);
     }
-    grids.push(current_grid);
-    grids.swap_remove(0);
-    return (draw_order, grids);
+    return Ok((draw_order, grids));
 }
 
-fn solve_problem_1(filename: &str) {
-    // Open file and read line by line
-    let (draw_order, mut grids) = parse_file(filename);
+fn solve_problem_1(draw_order: &[i32], grids: &[BingoGrid]) -> Answer {
+    let mut grids = grids.to_vec();
 
     let mut ans = 0;
     // Insert number one by one
-    for number in draw_order.split(",").map(|s| s.parse::<i32>().unwrap()) {
+    for &number in draw_order.iter() {
         let mut grid_won = false;
         for grid in grids.iter_mut() {
             grid.draw(number);
@@ -146,35 +119,43 @@ fn solve_problem_1(filename: &str) {
         }
     }
 
-    println!("Answer: {:?}", ans);
+    return Answer::from(ans);
 }
 
-fn solve_problem_2(filename: &str) {
-    let (draw_order, mut grids) = parse_file(filename);
+fn solve_problem_2(draw_order: &[i32], grids: &[BingoGrid]) -> Answer {
+    let mut grids = grids.to_vec();
 
     let mut ans = 0;
     // Insert number one by one
-    for number in draw_order.split(",").map(|s| s.parse::<i32>().unwrap()) {
+    for &number in draw_order.iter() {
         for grid in grids.iter_mut() {
             grid.draw(number);
         }
         // Remove winning grids
         if grids.len() != 1 {
-            grids = grids.into_iter().filter(|g| !g.wins()).collect();
+            grids.retain(|g| !g.wins());
         } else if grids[0].wins() {
             ans = grids[0].get_score(number);
             break;
         }
     }
-    println!("Answer: {:?}", ans);
+    return Answer::from(ans);
 }
 
-fn main() {
-    let arg = Arg::parse();
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = (Vec<i32>, Vec<BingoGrid>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_file(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(&input.0, &input.1);
+    }
 
-    match arg.challenge_num {
-        1 => { solve_problem_1(&arg.filename); },
-        2 => { solve_problem_2(&arg.filename); },
-        n => { panic!("[ERROR] Incorrect challenge number {}", n); }
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(&input.0, &input.1);
     }
 }
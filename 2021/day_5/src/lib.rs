@@ -0,0 +1,120 @@
+use aoc_core::{parse, Answer, Params, ParseError, Point2, Solution};
+use std::collections::HashMap;
+use std::cmp::{max, min};
+use std::str::FromStr;
+
+/// Line of vents, from one end to the other
+type Line = (Point2<i32>, Point2<i32>);
+
+fn add_horizontal_line_to_map(map: &mut HashMap<Point2<i32>, i32>, point_1: Point2<i32>, point_2: Point2<i32>) {
+    // Horizontal or vertical lines only
+    if point_1.x == point_2.x {
+        let mut current_y = min(point_1.y, point_2.y);
+        while current_y <= max(point_1.y, point_2.y) {
+            map.entry(
+                Point2::new(point_1.x, current_y)
+            ).and_modify(|counter| *counter += 1).or_insert(1);
+            current_y += 1;
+        }
+    } else if point_1.y == point_2.y {
+        let mut current_x = min(point_1.x, point_2.x);
+        while current_x <= max(point_1.x, point_2.x) {
+            map.entry(
+                Point2::new(current_x, point_1.y)
+            ).and_modify(|counter| *counter += 1).or_insert(1);
+            current_x += 1;
+        }
+    }
+}
+
+fn add_diagonal_line_to_map(map: &mut HashMap<Point2<i32>, i32>, point_1: Point2<i32>, point_2: Point2<i32>) {
+    if point_1.x > point_2.x {
+        add_diagonal_line_to_map(map, point_2, point_1);
+        return;
+    }
+    let coefficient = if point_1.x == point_2.x {
+        0
+    } else {
+        (point_2.y - point_1.y) / (point_2.x - point_1.x)
+    };
+
+    if coefficient.abs() == 1 {
+        let (mut x, mut y, max_x) = (point_1.x, point_1.y, point_2.x);
+        while x <= max_x {
+            map.entry(
+                Point2::new(x, y)
+            ).and_modify(|counter| *counter += 1).or_insert(1);
+            x += 1;
+            y += coefficient;
+        }
+    }
+}
+
+fn add_line_to_map(map: &mut HashMap<Point2<i32>, i32>, point_1: Point2<i32>, point_2: Point2<i32>) {
+    add_horizontal_line_to_map(map, point_1, point_2);
+    add_diagonal_line_to_map(map, point_1, point_2);
+}
+
+fn parse_input(input: &str) -> Result<Vec<Line>, ParseError> {
+    let mut lines = Vec::new();
+
+    // Read input line by line
+    for s in input.lines() {
+        let (point_1, point_2) = parse::split_once(s, " -> ")?;
+        lines.push((Point2::from_str(point_1)?, Point2::from_str(point_2)?));
+    }
+
+    return Ok(lines);
+}
+
+fn solve_problem_1(lines: &[Line]) -> Answer {
+    let mut ans = 0;
+    let mut map: HashMap<Point2<i32>, i32> = HashMap::new();
+
+    for &(point_1, point_2) in lines.iter() {
+        add_horizontal_line_to_map(&mut map, point_1, point_2);
+    }
+
+    for value in map.into_values() {
+        if value > 1 {
+            ans += 1;
+        }
+    }
+
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(lines: &[Line]) -> Answer {
+    let mut ans = 0;
+    let mut map: HashMap<Point2<i32>, i32> = HashMap::new();
+
+    for &(point_1, point_2) in lines.iter() {
+        add_line_to_map(&mut map, point_1, point_2);
+    }
+
+    for value in map.into_values() {
+        if value > 1 {
+            ans += 1;
+        }
+    }
+
+    return Answer::from(ans);
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<Line>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
+    }
+}
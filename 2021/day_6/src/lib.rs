@@ -0,0 +1,69 @@
+use aoc_core::{parse, Answer, Params, ParseError, Solution};
+
+/// Timers of the initial fish
+fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut timers = Vec::new();
+    for value in input.split(",") {
+        let timer = parse::number::<usize>(value)?;
+        if timer > 8 {
+            return Err(ParseError::new(value, "Timers range from 0 to 8"));
+        }
+        timers.push(timer);
+    }
+    return Ok(timers);
+}
+
+/// Simulate for simulation_duration days
+/// Return the number of fish by the end of the simulation
+fn simulate(timers: &[usize], simulation_duration: usize) -> u64 {
+    /*
+    Solution:
+        * Have an array of u64, each value is the number of fish that reproduce that that day
+            -> Each day, add arr[day%7] to new_born[day%9]
+        * Have an array of u64
+            -> Transfer new_born[day%9] to arr[day%7]
+    Answer is the sum of every value from both array
+    */
+
+    let mut fish_count: [u64; 7] = [0; 7];
+    let mut new_born_count: [u64; 9] = [0; 9];
+
+    for &timer in timers.iter() {
+        fish_count[timer] += 1;
+    }
+
+    for day in 0..simulation_duration {
+        fish_count[day%7] += new_born_count[day%9];
+        new_born_count[day%9] = fish_count[day%7];
+    }
+
+    return fish_count.iter().sum::<u64>() + new_born_count.iter().sum::<u64>();
+}
+
+fn solve_problem_1(timers: &[usize]) -> Answer {
+    let ans = simulate(timers, 80);
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(timers: &[usize]) -> Answer {
+    let ans = simulate(timers, 256);
+    return Answer::from(ans);
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
+    }
+}
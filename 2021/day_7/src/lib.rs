@@ -1,17 +1,7 @@
-use std::fs;
-use clap::{Parser};
+use aoc_core::{parse, Answer, Params, ParseError, Solution};
 use std::collections::BinaryHeap;
 use std::cmp::{Reverse, min};
 
-#[derive(Debug, Parser)]
-struct Arg {
-    /// Challenge to run (1 or 2)
-    challenge_num: u8,
-
-    /// Input file
-    filename: String,
-}
-
 #[derive(Debug, Clone)]
 struct MedianFinder {
     left: BinaryHeap<i32>,
@@ -44,7 +34,7 @@ impl MedianFinder {
     fn peek_right(&self) -> i32 {
         match self.right.peek() {
             Some(Reverse(value)) => { *value },
-            None => { std::i32::MAX },
+            None => { i32::MAX },
         }
     }
 
@@ -83,16 +73,15 @@ fn int_sum_to(number: i32) -> i32 {
     return (number * (number + 1)) / 2;
 }
 
-fn solve_problem_1(filename: &str) {
-    let mut ans = 0;
+fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    return parse::numbers(input, ",");
+}
 
-    let contents = match fs::read_to_string(filename) {
-        Ok(data) => { data },
-        Err(e) => { panic!("{}", e); }
-    };
+fn solve_problem_1(numbers: &[i32]) -> Answer {
+    let mut ans = 0;
 
     let mut median_finder = MedianFinder::new();
-    for number in contents.split(",").map(|s| s.parse::<i32>().unwrap()) {
+    for &number in numbers.iter() {
         median_finder.insert(number);
     }
 
@@ -104,16 +93,10 @@ fn solve_problem_1(filename: &str) {
         ans += (median - *value).abs();
     }
 
-    println!("Answer: {:?}", ans);
+    return Answer::from(ans);
 }
 
-fn solve_problem_2(filename: &str) {
-    let contents = match fs::read_to_string(filename) {
-        Ok(data) => { data },
-        Err(e) => { panic!("{}", e); }
-    };
-
-    let numbers: Vec<i32> = contents.split(",").map(|s| s.parse::<i32>().unwrap()).collect();
+fn solve_problem_2(numbers: &[i32]) -> Answer {
     let mut sum = 0;
     for number in numbers.iter() {
         sum += number;
@@ -128,15 +111,23 @@ fn solve_problem_2(filename: &str) {
     }
 
     let ans = min(ans_1, ans_2);
-    println!("Answer: {:?}", ans);
+    return Answer::from(ans);
 }
 
-fn main() {
-    let arg = Arg::parse();
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
 
-    match arg.challenge_num {
-        1 => { solve_problem_1(&arg.filename); },
-        2 => { solve_problem_2(&arg.filename); },
-        n => { panic!("[ERROR] Incorrect challenge number {}", n); }
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
     }
 }
@@ -1,51 +1,41 @@
-use std::fs::{File};
-use std::io::{BufReader, BufRead, Lines};
-use clap::{Parser};
+use aoc_core::{parse, Answer, Params, ParseError, Solution};
 use std::collections::HashMap;
-
-#[derive(Debug, Parser)]
-struct Arg {
-    /// Challenge to run (1 or 2)
-    challenge_num: u8,
-
-    /// Input file
-    filename: String,
-}
+use std::str::FromStr;
 
 #[derive(Debug, Clone)]
-struct RawSignal {
+pub struct RawSignal {
     numbers: [String; 10],
     code: [String; 4],
 }
 
-impl From<&str> for RawSignal {
-    fn from(s: &str) -> Self {
-        let split_pipe = s.split(" | ").collect::<Vec<&str>>();
-        if split_pipe.len() != 2 {
-            panic!("[ERROR] Cannot convert {} to RawSignal: expected 1 pipe, got {}!", s, split_pipe.len() - 1);
-        }
-        let v_numbers = split_pipe[0].split(" ").collect::<Vec<&str>>();
+impl FromStr for RawSignal {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (v_numbers, v_code) = parse::split_once(s, " | ")?;
+
+        let v_numbers = v_numbers.split(" ").collect::<Vec<&str>>();
         if v_numbers.len() != 10 {
-            panic!("[ERROR] Cannot convert {} to RawSignal: expected 10 numbers before pipe, got {}!", s, v_numbers.len());
+            return Err(ParseError::new(s, format!("Expected 10 numbers before pipe, got {}", v_numbers.len())));
         }
         let mut numbers: [String; 10] = Default::default();
         for (i, num) in v_numbers.iter().enumerate() {
             numbers[i] = String::from(*num);
         }
 
-        let v_code = split_pipe[1].split(" ").collect::<Vec<&str>>();
+        let v_code = v_code.split(" ").collect::<Vec<&str>>();
         if v_code.len() != 4 {
-            panic!("[ERROR] Cannot convert {} to RawSignal: expected 4 numbers after pipe, got {}!", s, v_code.len());
+            return Err(ParseError::new(s, format!("Expected 4 numbers after pipe, got {}", v_code.len())));
         }
         let mut code: [String; 4] = Default::default();
         for (i, num) in v_code.iter().enumerate() {
             code[i] = String::from(*num);
         }
 
-        Self {
+        Ok(Self {
             numbers,
             code,
-        }
+        })
     }
 }
 
@@ -168,17 +158,9 @@ impl DecodedSignal {
     }
 }
 
-fn read_lines(filename: &str) -> Lines<BufReader<File>> {
-    let file = match File::open(filename) {
-        Ok(f) => { f },
-        Err(e) => { panic!("[ERROR] {}", e) }
-    };
-    BufReader::new(file).lines()
-}
-
 fn sort_string(s: String) -> String {
     let mut chars: Vec<char> = s.chars().collect();
-    chars.sort_by(|a, b| a.cmp(b));
+    chars.sort();
     chars.into_iter().collect::<String>()
 }
 
@@ -200,45 +182,48 @@ fn is_included(needle: String, haystack: String) -> bool{
     return found;
 }
 
-fn solve_problem_1(filename: &str) {
+fn parse_input(input: &str) -> Result<Vec<RawSignal>, ParseError> {
+    // Read input line by line
+    return input.lines().map(RawSignal::from_str).collect();
+}
+
+fn solve_problem_1(signals: &[RawSignal]) -> Answer {
     let mut ans = 0;
 
-    // Open file and read line by line
-    let lines = read_lines(filename);
-    for line in lines {
-        if let Ok(s) = line {
-            let raw_signal = RawSignal::from(s.as_str());
-            let decoded_signal = raw_signal.decode();
-            ans += decoded_signal.count_decoded_easily();
-        }
+    for raw_signal in signals.iter() {
+        let decoded_signal = raw_signal.decode();
+        ans += decoded_signal.count_decoded_easily();
     }
 
-    println!("Answer: {:?}", ans);
+    return Answer::from(ans);
 }
 
-fn solve_problem_2(filename: &str) {
+fn solve_problem_2(signals: &[RawSignal]) -> Answer {
     let mut ans = 0;
 
-    // Open file and read line by line
-    let lines = read_lines(filename);
-    for line in lines {
-        if let Ok(s) = line {
-            let raw_signal = RawSignal::from(s.as_str());
-            let decoded_signal = raw_signal.decode();
-            let code = decoded_signal.get_code();
-            ans += code;
-        }
+    for raw_signal in signals.iter() {
+        let decoded_signal = raw_signal.decode();
+        let code = decoded_signal.get_code();
+        ans += code;
     }
 
-    println!("Answer: {:?}", ans);
+    return Answer::from(ans);
 }
 
-fn main() {
-    let arg = Arg::parse();
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<RawSignal>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
 
-    match arg.challenge_num {
-        1 => { solve_problem_1(&arg.filename); },
-        2 => { solve_problem_2(&arg.filename); },
-        n => { panic!("[ERROR] Incorrect challenge number {}", n); }
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
     }
 }
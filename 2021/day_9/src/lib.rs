@@ -0,0 +1,90 @@
+use aoc_core::grid::{self, Pos};
+use aoc_core::visualize::{self, Cell, Color};
+use aoc_core::{Answer, Frame, Grid, Params, ParseError, Solution};
+
+fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    return Grid::parse(input, grid::digit);
+}
+
+fn get_basin_size(map: &mut Grid<u32>, pos: Pos) -> u32 {
+    if map[pos] == 9 {
+        return 0;
+    }
+    map[pos] = 9;
+    let mut basin_size = 1;
+    for neighbor in map.neighbors4(pos) {
+        basin_size += get_basin_size(map, neighbor);
+    }
+    return basin_size;
+}
+
+/// Heights of the map, the highest ones (9) bounding the basins and marking
+/// the basins already measured
+fn draw_map(map: &Grid<u32>, basin_count: usize) -> Frame {
+    let frame = Frame::from_grid(map, |&height| {
+        let digit = char::from_digit(height, 10).unwrap();
+        if height == 9 { Cell::colored(digit, Color::Blue) } else { Cell::from(digit) }
+    });
+    return frame.titled(format!("{} basins found", basin_count));
+}
+
+fn get_basins(map: &mut Grid<u32>) -> Vec<u32> {
+    let mut basins: Vec<u32> = Vec::new();
+    for pos in map.positions() {
+        let basin_size = get_basin_size(map, pos);
+        if basin_size != 0 {
+            basins.push(basin_size);
+            visualize::frame(|| draw_map(map, basins.len()));
+        }
+    }
+    return basins;
+}
+
+fn get_biggest(array: &[u32], n: usize) -> Vec<u32> {
+    let mut biggest = vec![0; n];
+    for val in array.iter() {
+        let mut value = *val;
+        for max in biggest.iter_mut() {
+            if *max < value {
+                std::mem::swap(&mut *max, &mut value);
+            }
+        }
+    }
+    return biggest;
+}
+
+fn solve_problem_1(map: &Grid<u32>) -> Answer {
+    let mut ans = 0;
+    for (pos, &height) in map.iter() {
+        // Check neighbors
+        if map.neighbors4(pos).all(|neighbor| height < map[neighbor]) {
+            ans += height + 1;
+        }
+    }
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(map: &Grid<u32>) -> Answer {
+    let mut map = map.clone();
+    let basins = get_basins(&mut map);
+    let ans = get_biggest(&basins, 3).iter().product::<u32>();
+    return Answer::from(ans);
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Grid<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
+    }
+}
@@ -0,0 +1,52 @@
+use aoc_core::{parse, Answer, Params, ParseError, Solution};
+
+fn get_elves_calories(input: &str) -> Result<Vec<i32>, ParseError> {
+    // Each elf's inventory is a block of lines
+    parse::blocks(input, |inventory| inventory.lines().map(parse::number::<i32>).sum())
+}
+
+fn solve_problem_1(calories: &[i32]) -> Answer {
+    let mut ans = 0;
+
+    for &cal_cnt in calories.iter() {
+        if cal_cnt > ans {
+            ans = cal_cnt;
+        }
+    }
+
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(calories: &[i32]) -> Answer {
+    let mut ans_arr:[i32; 3] = [0; 3];
+
+    for &cal_cnt in calories.iter() {
+        let mut curr_cal = cal_cnt;
+        for val in ans_arr.iter_mut() {
+            if *val < curr_cal {
+                std::mem::swap(&mut *val, &mut curr_cal);
+            }
+        }
+    }
+
+    let ans = ans_arr.iter().sum::<i32>();
+    return Answer::from(ans);
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return get_elves_calories(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
+    }
+}
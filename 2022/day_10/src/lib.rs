@@ -0,0 +1,169 @@
+use aoc_core::visualize::{self, Cell, Color};
+use aoc_core::{ocr, parse, Answer, Frame, Grid, Params, ParseError, Point2, Solution};
+use std::str::FromStr;
+
+const SCREEN_WIDTH: usize = 40;
+const SCREEN_HEIGHT: usize = 6;
+
+#[derive(Debug, Copy, Clone)]
+struct Processor {
+    cycle_count: i32,
+    registry: i32,
+}
+
+impl Processor {
+    fn signal_strength(&self) -> i32 {
+        return (self.cycle_count) * self.registry
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum ProcessorInstruction {
+    AddX(i32),
+    Noop,
+}
+
+impl FromStr for ProcessorInstruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(" ") {
+            Some(("addx", amount)) => { Ok(Self::AddX(parse::number::<i32>(amount)?)) },
+            None if s == "noop" => { Ok(Self::Noop) },
+            _ => { Err(ParseError::new(s, format!("{} is not a valid instruction", s))) }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Screen {
+    pixels: Grid<char>,
+}
+
+impl Screen {
+    fn new() -> Self {
+        Self {
+            pixels: Grid::new(SCREEN_WIDTH, SCREEN_HEIGHT, '.'),
+        }
+    }
+
+    fn get_rows(&self) -> Vec<String> {
+        self.pixels.render(|&pixel| pixel)
+    }
+
+    fn draw(&self, cpu: Processor) -> Frame {
+        let frame = Frame::from_grid(&self.pixels, |&pixel| {
+            if pixel == '#' { Cell::colored('#', Color::Green) } else { Cell::colored(pixel, Color::Gray) }
+        });
+        return frame.titled(format!("Cycle {}, X = {}", cpu.cycle_count, cpu.registry));
+    }
+
+    fn update_screen(&mut self, cpu: Processor) {
+        if cpu.cycle_count > 240 {
+            return;
+        }
+        let cycle_count = cpu.cycle_count - 1;
+
+        // row and col of current screen pixel to draw
+        let row = cycle_count / 40;
+        let col = cycle_count % 40;
+
+        let pixel = Point2::new(col as usize, row as usize);
+        if cpu.registry >= col - 1 && cpu.registry <= col + 1 {
+            self.pixels[pixel] = '#';
+        }
+
+        visualize::frame(|| self.draw(cpu));
+    }
+}
+
+fn get_signal_strength(cpu: Processor) -> i32 {
+    if ((cpu.cycle_count - 20) % 40) == 0 && cpu.cycle_count <= 220 {
+        return cpu.signal_strength();
+    }
+    return 0;
+}
+
+fn parse_input(input: &str) -> Result<Vec<ProcessorInstruction>, ParseError> {
+    // Read input line by line
+    return input.lines().map(ProcessorInstruction::from_str).collect();
+}
+
+fn solve_problem_1(instructions: &[ProcessorInstruction]) -> Answer {
+    let mut ans = 0;
+
+    let mut cpu = Processor {
+        cycle_count: 1,
+        registry: 1,
+    };
+
+    for &instruction in instructions.iter() {
+        match instruction {
+            ProcessorInstruction::Noop => {
+                cpu.cycle_count += 1;
+                ans += get_signal_strength(cpu);
+            },
+            ProcessorInstruction::AddX(amount) => {
+                cpu.cycle_count += 1;
+                ans += get_signal_strength(cpu);
+                cpu.cycle_count += 1;
+                cpu.registry += amount;
+                ans += get_signal_strength(cpu);
+            }
+        }
+    }
+
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(instructions: &[ProcessorInstruction]) -> Answer {
+    let mut screen = Screen::new();
+    let mut cpu = Processor {
+        cycle_count: 1,
+        registry: 1,
+    };
+
+    screen.update_screen(cpu);
+
+    for &instruction in instructions.iter() {
+        match instruction {
+            ProcessorInstruction::Noop => {
+                cpu.cycle_count += 1;
+                screen.update_screen(cpu);
+            },
+            ProcessorInstruction::AddX(amount) => {
+                cpu.cycle_count += 1;
+                screen.update_screen(cpu);
+                cpu.cycle_count += 1;
+                cpu.registry += amount;
+                screen.update_screen(cpu);
+            }
+        }
+    }
+
+    match ocr::read(&screen.pixels, |&pixel| pixel == '#') {
+        Ok(letters) => { return Answer::Text(letters); },
+        // Screens not showing letters at all (e.g. the pattern of the example)
+        // are left to be compared as they are
+        Err(e) if !e.is_letter_sized() => { return Answer::Screen(screen.get_rows()); },
+        Err(e) => { return Answer::Failed(e.to_string()); }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<ProcessorInstruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
+    }
+}
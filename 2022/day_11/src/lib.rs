@@ -0,0 +1,208 @@
+use aoc_core::{input, math, parse, scan, Answer, Param, Params, ParseError, Solution};
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add(i64),
+    Multiply(i64),
+    Square,
+}
+
+impl Operation {
+    fn apply(&self, value: i64) -> i64 {
+        let result = match self {
+            Operation::Add(x) => { value.checked_add(*x) },
+            Operation::Multiply(x) => { value.checked_mul(*x) },
+            Operation::Square => { value.checked_mul(value) },
+        };
+        match result {
+            Some(result) => { return result; },
+            None => { panic!("[ERROR] Worry level {} overflows with {:?}", value, self); }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Test {
+    // The divisor
+    divisible_by: i64,
+    // If true: pass to monkey ...
+    if_true: usize,
+    // If false: pass to monkey ...
+    if_false: usize,
+}
+
+impl Test {
+    fn eval(&self, value: i64) -> usize {
+        if value % self.divisible_by == 0 {
+            return self.if_true;
+        } else {
+            return self.if_false;
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<i64>,
+    operation: Operation,
+    test: Test,
+    inspection_count: i64,
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<&str>>();
+        if lines.len() != 6 {
+            return Err(ParseError::new(s, format!("Incorrect line number for a monkey: expected 6 got {}", lines.len())));
+        }
+        // Get the items from the second line
+        let items = parse_items(lines[1])?;
+        // Get the operation from the third line
+        let operation = parse_operation(lines[2])?;
+        // Get the test from the other lines
+        let test = parse_test(lines[3], lines[4], lines[5])?;
+
+        Ok(
+            Self {
+                items,
+                operation,
+                test,
+                inspection_count: 0,
+            }
+        )
+    }
+}
+
+fn parse_items(line: &str) -> Result<Vec<i64>, ParseError> {
+    let items = parse::scan(line.trim(), "Starting items: {}")?;
+    return parse::numbers::<i64>(items[0], ",");
+}
+
+fn parse_operation(line: &str) -> Result<Operation, ParseError> {
+    let fields = parse::scan(line.trim(), "Operation: new = old {} {}")?;
+    let operation = match (fields[0], fields[1]) {
+        ("*", "old") => { Operation::Square },
+        ("+", num) => { Operation::Add(parse::number::<i64>(num)?) },
+        ("*", num) => { Operation::Multiply(parse::number::<i64>(num)?) },
+        _ => { return Err(ParseError::new(fields[0], "Expected '+ <number>', '* <number>' or '* old'")); }
+    };
+    return Ok(operation);
+}
+
+fn parse_test(test_line: &str, if_true_line: &str, if_false_line: &str) -> Result<Test, ParseError> {
+    let if_true = scan!(if_true_line.trim(), "If true: throw to monkey {}", usize)?;
+    let if_false = scan!(if_false_line.trim(), "If false: throw to monkey {}", usize)?;
+    let divisible_by = scan!(test_line.trim(), "Test: divisible by {}", i64)?;
+    if divisible_by <= 0 {
+        return Err(ParseError::new(test_line, "The divisor must be positive"));
+    }
+
+    Ok(Test {
+        divisible_by,
+        if_true,
+        if_false,
+    })
+}
+
+fn get_monkey_business_level(monkeys: &[Monkey], n: i32) -> i64{
+    let mut best: Vec<i64> = vec![0; n as usize];
+
+    for monkey in monkeys.iter() {
+        let mut value = monkey.inspection_count;
+        for val in best.iter_mut() {
+            if *val < value {
+                std::mem::swap(&mut *val, &mut value);
+            }
+        }
+    }
+
+    return best.iter().product();
+}
+
+fn simulate_rounds(monkeys: &mut [Monkey], n: i32, relief: bool) {
+    // Worry levels only matter through their divisibility by the divisors of
+    // the tests, which is kept modulo their lcm
+    let modulus = match math::lcm_all(monkeys.iter().map(|monkey| monkey.test.divisible_by)) {
+        Some(modulus) => { modulus },
+        None => { panic!("[ERROR] The least common multiple of the divisors does not fit in an i64"); }
+    };
+
+    for _ in 0..n {
+        for i_monkey in 0..monkeys.len() {
+            monkeys[i_monkey].inspection_count += monkeys[i_monkey].items.len() as i64;
+            // Go though each item for current monkey
+            let mut i = 0;
+            while i < monkeys[i_monkey].items.len() {
+                let mut item = monkeys[i_monkey].items[i];
+                item = monkeys[i_monkey].operation.apply(item);
+                if relief {
+                    item /= 3;
+                } else {
+                    item %= modulus;
+                }
+                let give_to = monkeys[i_monkey].test.eval(item);
+                if give_to != i_monkey {
+                    monkeys[give_to].items.push(item);
+                    monkeys[i_monkey].items.remove(i);
+                    continue;
+                }
+                i += 1;
+            }
+        }
+    }
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    // Each monkey is described by a block of lines
+    let blocks = input::paragraphs(input);
+    let monkeys = blocks.iter()
+        .map(|block| Monkey::from_str(block))
+        .collect::<Result<Vec<Monkey>, ParseError>>()?;
+
+    for (block, monkey) in blocks.iter().zip(monkeys.iter()) {
+        if monkey.test.if_true >= monkeys.len() || monkey.test.if_false >= monkeys.len() {
+            return Err(ParseError::new(block, format!("Items can only be thrown to monkeys 0 to {}", monkeys.len() - 1)));
+        }
+    }
+    return Ok(monkeys);
+}
+
+fn solve_problem_1(monkeys: &[Monkey], rounds: i32) -> Answer {
+    let mut monkeys = monkeys.to_vec();
+    simulate_rounds(&mut monkeys, rounds, true);
+    let ans = get_monkey_business_level(&monkeys, 2);
+
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(monkeys: &[Monkey], rounds: i32) -> Answer {
+    let mut monkeys = monkeys.to_vec();
+    simulate_rounds(&mut monkeys, rounds, false);
+    let ans = get_monkey_business_level(&monkeys, 2);
+
+    return Answer::from(ans);
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn params(&self) -> Vec<Param> {
+        return vec![Param::per_part::<i32>("rounds", "Number of rounds to simulate", "20", "10000")];
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_monkeys(input);
+    }
+
+    fn part1(&self, input: &Self::Input, params: &Params) -> Answer {
+        return solve_problem_1(input, params.get("rounds"));
+    }
+
+    fn part2(&self, input: &Self::Input, params: &Params) -> Answer {
+        return solve_problem_2(input, params.get("rounds"));
+    }
+}
@@ -0,0 +1,66 @@
+use aoc_core::grid::Pos;
+use aoc_core::{search, Answer, Grid, Params, ParseError, Solution};
+
+/// Map of the elevations, with the start and the end positions
+type Heightmap = (Grid<u8>, Pos, Pos);
+
+fn elevation(c: char) -> Result<u8, String> {
+    match c {
+        'S' => { Ok(b'a') },
+        'E' => { Ok(b'z') },
+        'a'..='z' => { Ok(c as u8) },
+        _ => { Err(String::from("Expected an elevation from a to z")) },
+    }
+}
+
+fn parse_input(input: &str) -> Result<Heightmap, ParseError> {
+    let squares = Grid::parse(input, |c| elevation(c).map(|_| c))?;
+    let find = |marker: char| squares.iter().find(|(_, &c)| c == marker).map(|(pos, _)| pos);
+    let (start, end) = match (find('S'), find('E')) {
+        (Some(start), Some(end)) => { (start, end) },
+        _ => { return Err(ParseError::new(input, "Expected a start (S) and an end (E)")); }
+    };
+    return Ok((squares.map(|&c| elevation(c).unwrap()), start, end));
+}
+
+/// Fewest steps to the end from the closest of the starts
+fn fewest_steps(map: &Grid<u8>, starts: impl IntoIterator<Item = Pos>, end: Pos) -> Answer {
+    // A step can climb at most one elevation up, but go down any amount
+    let climbable = |pos: &Pos| {
+        let (pos, highest) = (*pos, map[*pos] + 1);
+        return map.neighbors4(pos).filter(move |&neighbor| map[neighbor] <= highest);
+    };
+    let res = search::bfs(starts, climbable, |&pos| pos == end);
+    match res.goal_cost() {
+        Some(steps) => { return Answer::from(steps); },
+        None => { panic!("[ERROR] The end can not be reached"); }
+    }
+}
+
+fn solve_problem_1(map: &Grid<u8>, start: Pos, end: Pos) -> Answer {
+    return fewest_steps(map, [start], end);
+}
+
+fn solve_problem_2(map: &Grid<u8>, end: Pos) -> Answer {
+    // Searching from every lowest square at once finds the closest one
+    let lowest = map.iter().filter(|(_, &height)| height == b'a').map(|(pos, _)| pos);
+    return fewest_steps(map, lowest, end);
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Heightmap;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(&input.0, input.1, input.2);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(&input.0, input.2);
+    }
+}
@@ -0,0 +1,171 @@
+use aoc_core::visualize::{self, Cell, Color};
+use aoc_core::{Answer, Frame, Grid, Params, ParseError, Point2, Solution};
+use std::cmp::{min, max};
+use std::str::FromStr;
+
+const SAND_SPAWN_POSITION: usize = 500;
+
+#[derive(Clone, PartialEq)]
+enum Element {
+    Air,
+    Rock,
+    Sand,
+}
+
+fn draw_map(map: &Grid<Element>, sand_count: u32) -> Frame {
+    let frame = Frame::from_grid(map, |unit| {
+        match unit {
+            Element::Air => { Cell::from('.') },
+            Element::Rock => { Cell::colored('#', Color::Gray) },
+            Element::Sand => { Cell::colored('o', Color::Yellow) },
+        }
+    });
+    return frame.titled(format!("{} units of sand at rest", sand_count));
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<Point2<usize>>>, ParseError> {
+    // Read the file and build a Vec of points
+    let mut rock_formations = Vec::new();
+    for s in input.lines() {
+        let rock_formation = s.split(" -> ")
+            .map(Point2::from_str)
+            .collect::<Result<Vec<Point2<usize>>, ParseError>>()?;
+        for pair in rock_formation.windows(2) {
+            if pair[0].x != pair[1].x && pair[0].y != pair[1].y {
+                return Err(ParseError::new(s, "Rock paths are made of horizontal and vertical lines"));
+            }
+        }
+        rock_formations.push(rock_formation);
+    }
+    return Ok(rock_formations);
+}
+
+fn build_map(rock_formations: &[Vec<Point2<usize>>], infinite_plane: bool) -> (Grid<Element>, usize) {
+    let mut rock_formations = rock_formations.to_vec();
+
+    // Find the lowest X in the Vec -> offset
+    // Find the hightest X and Y (for map dimensions)
+    let mut x_min = usize::MAX;
+    let mut x_max = usize::MIN;
+    let mut y_max = usize::MIN;
+    for rock_formation in rock_formations.iter() {
+        for coords in rock_formation.iter() {
+            x_min = min(x_min, coords.x);
+            x_max = max(x_max, coords.x);
+            y_max = max(y_max, coords.y);
+        }
+    }
+
+    if infinite_plane {
+        y_max += 2;
+        rock_formations.push(
+            vec![
+                Point2::new(SAND_SPAWN_POSITION - (y_max + 1), y_max),
+                Point2::new(SAND_SPAWN_POSITION + (y_max + 1), y_max),
+            ]
+        );
+        x_max = max(x_max, SAND_SPAWN_POSITION + (y_max + 1));
+        x_min = min(x_min, SAND_SPAWN_POSITION - (y_max + 1));
+    }
+    let mut map = Grid::new((x_max + 1) - x_min, y_max + 1, Element::Air);
+
+    for rock_formation in rock_formations.iter() {
+        for i in 0..rock_formation.len() - 1 {
+            if rock_formation[i].x == rock_formation[i + 1].x {
+                let x = rock_formation[i].x;
+                let bottom = min(rock_formation[i].y, rock_formation[i + 1].y);
+                let top = max(rock_formation[i].y, rock_formation[i + 1].y);
+                for y in bottom..=top {
+                    map[Point2::new(x - x_min, y)] = Element::Rock;
+                }
+            } else {
+                let y = rock_formation[i].y;
+                let left = min(rock_formation[i].x, rock_formation[i + 1].x);
+                let right = max(rock_formation[i].x, rock_formation[i + 1].x);
+                for x in left..=right {
+                    map[Point2::new(x - x_min, y)] = Element::Rock;
+                }
+            }
+        }
+    }
+    return (map, SAND_SPAWN_POSITION - x_min);
+}
+
+fn simulate_sand_pour(map: &mut Grid<Element>, x_spawn: usize) -> u32 {
+    let mut sand_count = 0;
+    let y_max = map.height();
+    let x_max = map.width();
+
+    loop {
+        let mut y_sand = 0;
+        let mut x_sand = x_spawn;
+        loop {
+            if map[Point2::new(x_sand, y_sand)] != Element::Air {
+                return sand_count;
+            }
+            if y_sand + 1 >= y_max {
+                return sand_count;
+            }
+            if map[Point2::new(x_sand, y_sand + 1)] == Element::Air {
+                y_sand += 1;
+                continue;
+            }
+            if x_sand != 0 && map[Point2::new(x_sand - 1, y_sand + 1)] == Element::Air {
+                y_sand += 1;
+                x_sand -= 1;
+                continue;
+            }
+
+            if x_sand + 1 >= x_max || (x_sand == 0 && map[Point2::new(x_sand + 1, y_sand)] != Element::Air) {
+                // Falling on the right or the left
+                return sand_count;
+            }
+
+            if x_sand + 1 != x_max && map[Point2::new(x_sand + 1, y_sand + 1)] == Element::Air {
+                y_sand += 1;
+                x_sand += 1;
+                continue;
+            }
+            break;
+        }
+        map[Point2::new(x_sand, y_sand)] = Element::Sand;
+        sand_count += 1;
+        visualize::frame(|| draw_map(map, sand_count));
+    }
+}
+
+fn solve_problem_1(rock_formations: &[Vec<Point2<usize>>]) -> Answer {
+    let (mut map, x_sand) = build_map(rock_formations, false);
+
+    visualize::frame(|| draw_map(&map, 0));
+
+    let ans = simulate_sand_pour(&mut map, x_sand);
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(rock_formations: &[Vec<Point2<usize>>]) -> Answer {
+    let (mut map, x_sand) = build_map(rock_formations, true);
+
+    visualize::frame(|| draw_map(&map, 0));
+
+    let ans = simulate_sand_pour(&mut map, x_sand);
+    return Answer::from(ans);
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Vec<Point2<usize>>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
+    }
+}
@@ -12,22 +12,15 @@ dir_name=${PWD##*/}
 
 dir_name=$(echo "${dir_name}" | sed 's/_/ /g')
 
+# Every day is run through the workspace runner
+year=$(basename "$(dirname "${PWD}")")
+day_num=${PWD##*_}
+
 printf "${LGREEN}------------------ BUILDING ${dir_name^^} ------------------${NC}\n"
-cargo build --release
+cargo build --release -p aoc
 printf "${LGREEN}--------------- DONE BUILDING ${dir_name^^} ----------------${NC}\n\n"
 
 printf "${LGREEN}------------- RUNNING ${dir_name^^} TEST CASES -------------${NC}\n"
-
-printf "\n${LRED}----------- TEST CASE: control_input.txt -----------${NC}\n"
-printf "${LCYAN}# CHALLENGE 1${NC}\n"
-./target/release/advent_of_code 1 control_input.txt 10
-printf "\n${LCYAN}# CHALLENGE 2${NC}\n"
-./target/release/advent_of_code 2 control_input.txt 20
-
-printf "\n${LRED}--------------- TEST CASE: input.txt ---------------${NC}\n"
-printf "${LCYAN}# CHALLENGE 1${NC}\n"
-./target/release/advent_of_code 1 input.txt 2000000
-printf "\n${LCYAN}# CHALLENGE 2${NC}\n"
-./target/release/advent_of_code 2 input.txt 4000000
-
-printf "\n${LGREEN}----------- DONE RUNNING ${dir_name^^} TEST CASES -----------${NC}\n"
+# Compares the answers of every input to the ones recorded in answers.toml
+../../target/release/aoc check ${year} ${day_num}
+printf "${LGREEN}----------- DONE RUNNING ${dir_name^^} TEST CASES -----------${NC}\n"
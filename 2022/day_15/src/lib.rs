@@ -0,0 +1,90 @@
+use aoc_core::{scan, Answer, Interval, IntervalSet, Param, Params, ParseError, Point2, Solution};
+use std::collections::HashSet;
+
+/// Position of a sensor and of the closest beacon it detects
+type Report = (Point2<i32>, Point2<i32>);
+
+fn get_tunning_frequency(position: Point2<i32>) -> u64 {
+    (position.x as u64) * 4000000 + (position.y as u64)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Report>, ParseError> {
+    let mut ret = Vec::new();
+    for s in input.lines() {
+        let report = scan!(s, "Sensor at {}: closest beacon is at {}", Point2<i32>, Point2<i32>)?;
+        ret.push(report);
+    }
+    return Ok(ret);
+}
+
+/// Positions of a row within the range of a sensor, if any
+fn get_sensor_coverage(sensor_pos: Point2<i32>, beacon_pos: Point2<i32>, row: i32) -> Option<Interval> {
+    let spare_distance = sensor_pos.manhattan(beacon_pos) - (sensor_pos.y - row).abs();
+    if spare_distance < 0 {
+        return None;
+    }
+    return Some(Interval::new((sensor_pos.x - spare_distance) as i64, (sensor_pos.x + spare_distance) as i64));
+}
+
+fn get_row_coverage(positions: &[Report], row: i32) -> usize {
+    let covered = positions.iter()
+        .filter_map(|&(sensor_pos, beacon_pos)| get_sensor_coverage(sensor_pos, beacon_pos, row))
+        .collect::<IntervalSet>();
+    // Positions of the known beacons can not be the ones of missing beacons
+    let beacons = positions.iter()
+        .map(|&(_, beacon_pos)| beacon_pos)
+        .filter(|beacon_pos| beacon_pos.y == row && covered.contains(beacon_pos.x as i64))
+        .collect::<HashSet<Point2<i32>>>();
+    return covered.len() as usize - beacons.len();
+}
+
+fn find_distress_beacon(positions: &[Report], extremum: Point2<i32>) -> Point2<i32> {
+    let columns = Interval::new(0, extremum.x as i64);
+    for y in 0..=extremum.y {
+        let covered = positions.iter()
+            .filter_map(|&(sensor_pos, beacon_pos)| get_sensor_coverage(sensor_pos, beacon_pos, y))
+            .collect::<IntervalSet>();
+        let gap = covered.gaps(columns).next();
+        if let Some(gap) = gap {
+            return Point2::new(gap.start as i32, y);
+        }
+    }
+
+    return extremum;
+}
+
+fn solve_problem_1(positions: &[Report], row_number: i32) -> Answer {
+    let ans = get_row_coverage(positions, row_number);
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(positions: &[Report], max_pos: i32) -> Answer {
+    let distress_beacon = find_distress_beacon(positions, Point2::new(max_pos, max_pos));
+    let ans = get_tunning_frequency(distress_beacon);
+    return Answer::from(ans);
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Report>;
+
+    fn params(&self) -> Vec<Param> {
+        return vec![
+            Param::new::<i32>("row", "Row to analyze for challenge 1", "2000000"),
+            Param::new::<i32>("max", "Maximum possible beacon position for challenge 2", "4000000"),
+        ];
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, params: &Params) -> Answer {
+        return solve_problem_1(input, params.get("row"));
+    }
+
+    fn part2(&self, input: &Self::Input, params: &Params) -> Answer {
+        return solve_problem_2(input, params.get("max"));
+    }
+}
@@ -1,21 +1,14 @@
-use std::fs::{File};
-use std::io::{Read};
-use clap::{Parser};
+use aoc_core::visualize::{self, Cell, Color};
+use aoc_core::{cycle, Answer, Direction, Frame, Param, Params, ParseError, Solution};
 use std::cmp::{max};
-use std::collections::HashMap;
 
 const CHAMBER_WIDTH: usize = 7;
+/// Rows at the top of the chamber telling whether its state repeats
+const FINGERPRINT_ROWS: usize = 100;
+/// Rows at the top of the chamber shown by the frames
+const VIEW_ROWS: usize = 30;
 const ROCK_ORDER: [Shape; 5] = [Shape::Dash, Shape::Plus, Shape::ReverseL, Shape::Pipe, Shape::Square];
 
-#[derive(Debug, Parser)]
-struct Arg {
-    /// Challenge to run (1 or 2)
-    challenge_num: u8,
-
-    /// Input file
-    filename: String,
-}
-
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
 enum Shape {
     Dash,
@@ -37,13 +30,8 @@ impl Shape {
     }
 }
 
-#[derive(Copy, Clone)]
-enum Direction {
-    Left,
-    Right,
-    Down,
-}
-
+/// Rows of the chamber from the floor up, so that moving a rock
+/// `Direction::Down` lowers its `y` (unlike `Direction::offset`)
 #[derive(Debug)]
 struct Chamber {
     content: Vec<[bool; CHAMBER_WIDTH]>,
@@ -93,7 +81,7 @@ impl Chamber {
         }
     }
 
-    fn drop_rock(&mut self, rock: Shape, moves: &Vec<Direction>, mut i_move: usize) -> usize {
+    fn drop_rock(&mut self, rock: Shape, moves: &[Direction], mut i_move: usize) -> usize {
         let mut rock_y = self.height + 3;
         let mut rock_x = 2;
 
@@ -164,6 +152,7 @@ impl Chamber {
                     return (x, y);
                 }
             },
+            Direction::Up => { panic!("[ERROR] Rocks never move up"); }
         }
     }
 
@@ -240,6 +229,7 @@ impl Chamber {
                     return (x, y);
                 }
             },
+            Direction::Up => { panic!("[ERROR] Rocks never move up"); }
         }
     }
 
@@ -308,6 +298,7 @@ impl Chamber {
                     return (x, y);
                 }
             },
+            Direction::Up => { panic!("[ERROR] Rocks never move up"); }
         }
     }
 
@@ -354,6 +345,7 @@ impl Chamber {
                     return (x, y);
                 }
             },
+            Direction::Up => { panic!("[ERROR] Rocks never move up"); }
         }
     }
 
@@ -413,105 +405,97 @@ impl Chamber {
                     return (x, y);
                 }
             },
+            Direction::Up => { panic!("[ERROR] Rocks never move up"); }
         }
     }
 
-    fn update_cache(&mut self, cache: &mut Cache, i_rock: usize, i_move: usize) {
-        if self.height > 100 {
-            let mut cache_arr = [[false; CHAMBER_WIDTH]; 100];
-            for i in self.height - 100..self.height {
-                cache_arr[i - (self.height - 100)] = self.content[i];
+    /// Rocks of the top rows of the chamber, the view following the top of
+    /// the tower
+    fn draw(&self, nb_rocks: usize) -> Frame {
+        let mut frame = Frame::new().titled(format!("{} rocks, {} units tall", nb_rocks, self.height));
+        for (y, row) in (self.height.saturating_sub(VIEW_ROWS)..self.height).rev().enumerate() {
+            for (x, &rock) in self.content[row].iter().enumerate() {
+                let cell = if rock { Cell::colored('#', Color::Cyan) } else { Cell::from('.') };
+                frame.plot(x as i64, y as i64, cell);
             }
-            cache.insert((ROCK_ORDER[i_rock % 5], i_move, cache_arr), (i_rock, self.height));
         }
+        return frame;
     }
 
-    fn check_cache(&mut self, cache: &Cache, i_rock: &mut usize, i_move: usize, nb_rocks: usize) -> usize {
-        if self.height <= 100 {
-            return 0;
-        }
-
-        let mut cache_arr = [[false; CHAMBER_WIDTH]; 100];
-        for i in self.height - 100..self.height {
-            cache_arr[i - (self.height - 100)] = self.content[i];
-        }
-
-        match cache.get(&(ROCK_ORDER[*i_rock % 5], i_move, cache_arr)) {
-            None => { 0 },
-            Some((nb_rock, last_height)) => {
-                let delta_rocks = *i_rock - nb_rock;
-                let delta_height = self.height - last_height;
-                let skippable = (nb_rocks - *i_rock) / delta_rocks;
-                *i_rock += skippable * delta_rocks;
-                return delta_height * skippable;
-            }
+    /// Top rows of the chamber, one bit per cell, the rows under the floor
+    /// being full
+    fn top_rows(&self) -> [u8; FINGERPRINT_ROWS] {
+        let mut rows = [0; FINGERPRINT_ROWS];
+        for (i, row) in rows.iter_mut().enumerate() {
+            *row = match self.height.checked_sub(i + 1) {
+                Some(y) => { self.content[y].iter().fold(0, |bits, &cell| (bits << 1) | cell as u8) },
+                None => { u8::MAX },
+            };
         }
+        return rows;
     }
 }
 
-fn parse_input(filename: &str) -> Vec<Direction> {
-    let mut data = String::new();
-    let mut f = File::open(filename).expect("Unable to open file");
-    f.read_to_string(&mut data).expect("Unable to read string");
-
+fn parse_input(input: &str) -> Result<Vec<Direction>, ParseError> {
     let mut moves = Vec::new();
-    for character in data.chars() {
+    for (index, character) in input.char_indices() {
         match character {
             '<' => { moves.push(Direction::Left); },
             '>' => { moves.push(Direction::Right); },
-            _ => { panic!("[ERROR] Unexpected move: {}", character); }
+            _ => {
+                let part = &input[index..index + character.len_utf8()];
+                return Err(ParseError::new(part, format!("Unexpected move {:?}", character)));
+            }
         }
     }
 
-    return moves;
+    return Ok(moves);
 }
 
-// (Shape + movement + last 100 rows) => (move number, chamber height)
-type Cache = HashMap<(Shape, usize, [[bool; CHAMBER_WIDTH]; 100]), (usize, usize)>;
-
-fn simulate(chamber: &mut Chamber, moves: &Vec<Direction>, nb_rocks: usize) {
-    let mut i_rock = 0;
-    let mut i_move = 0;
-    let mut cache: Cache = HashMap::new();
-    let mut total_height = 0;
-    while i_rock < nb_rocks {
-        // Drop rock
-        i_move = chamber.drop_rock(ROCK_ORDER[i_rock % 5], moves, i_move);
-        if total_height == 0 {
-            let skipped_height = chamber.check_cache(&cache, &mut i_rock, i_move, nb_rocks);
-            if skipped_height > 0 {
-                total_height = skipped_height;
-            }
-        }
-        // Update cache
-        chamber.update_cache(&mut cache, i_rock, i_move);
-        i_rock += 1;
-    }
-    chamber.height += total_height;
+/// Chamber, number of rocks dropped and index of the next move
+type State = (Chamber, usize, usize);
+
+/// Height of the chamber after `nb_rocks` rocks, skipping ahead once the
+/// top of the chamber repeats for the same rock and move
+fn simulate(moves: &[Direction], nb_rocks: usize) -> i64 {
+    let drop = |(chamber, i_rock, i_move): &mut State| {
+        *i_move = chamber.drop_rock(ROCK_ORDER[*i_rock % 5], moves, *i_move);
+        *i_rock += 1;
+        visualize::frame(|| chamber.draw(*i_rock));
+    };
+    let fingerprint = |(chamber, i_rock, i_move): &State| (*i_rock % 5, *i_move, chamber.top_rows());
+    let height = |(chamber, _, _): &State| chamber.height as i64;
+    return cycle::extrapolate(&mut (Chamber::new(), 0, 0), nb_rocks, drop, fingerprint, height);
 }
 
-fn solve_problem_1(filename: &str) {
-    let moves = parse_input(filename);
-    let mut chamber = Chamber::new();
-    simulate(&mut chamber, &moves, 2022);
-    let ans = chamber.height;
-    println!("Answer: {:?}", ans);
+fn solve_problem_1(moves: &[Direction], nb_rocks: usize) -> Answer {
+    let ans = simulate(moves, nb_rocks);
+    return Answer::from(ans);
 }
 
-fn solve_problem_2(filename: &str) {
-    let moves = parse_input(filename);
-    let mut chamber = Chamber::new();
-    simulate(&mut chamber, &moves, 1000000000000);
-    let ans = chamber.height;
-    println!("Answer: {:?}", ans);
+fn solve_problem_2(moves: &[Direction], nb_rocks: usize) -> Answer {
+    let ans = simulate(moves, nb_rocks);
+    return Answer::from(ans);
 }
 
-fn main() {
-    let arg = Arg::parse();
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Direction>;
+
+    fn params(&self) -> Vec<Param> {
+        return vec![Param::per_part::<usize>("rocks", "Number of rocks to drop", "2022", "1000000000000")];
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, params: &Params) -> Answer {
+        return solve_problem_1(input, params.get("rocks"));
+    }
 
-    match arg.challenge_num {
-        1 => { solve_problem_1(&arg.filename); },
-        2 => { solve_problem_2(&arg.filename); },
-        n => { panic!("[ERROR] Incorrect challenge number {}", n); }
+    fn part2(&self, input: &Self::Input, params: &Params) -> Answer {
+        return solve_problem_2(input, params.get("rocks"));
     }
 }
@@ -0,0 +1,131 @@
+use aoc_core::{Answer, Param, Params, ParseError, Point3, Solution};
+use std::str::FromStr;
+
+/// Lava and air cubes, indexed by z then y then x
+pub type Map = Vec<Vec<Vec<MapTileState>>>;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MapTileState {
+    Lava,
+    Air,
+    Visited,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Point3<usize>>, ParseError> {
+    return input.lines().map(Point3::from_str).collect();
+}
+
+fn build_map(cubes: &[Point3<usize>], map_size: usize) -> Map {
+    // Instead of having a fixed sized map we could find the min and max values
+    // in each dimension and create the smallest possible map thus saving some
+    // memory (but I'm too lazy to do that right now)
+    let mut map = vec![vec![vec![MapTileState::Air; map_size]; map_size]; map_size];
+    for cube in cubes.iter() {
+        if cube.x.max(cube.y).max(cube.z) + 2 >= map_size {
+            panic!("[ERROR] Cube {} does not fit in a map of size {}, increase the size parameter", cube, map_size);
+        }
+        // Add 1 to coords to be sure that lava does not touch the edge of our map
+        map[cube.z + 1][cube.y + 1][cube.x + 1] = MapTileState::Lava;
+    }
+    return map;
+}
+
+fn tile(map: &Map, cube: Point3<usize>) -> MapTileState {
+    return map[cube.z][cube.y][cube.x];
+}
+
+/// Cubes sharing a face with a cube, inside the map
+fn neighbors(map: &Map, cube: Point3<usize>) -> impl Iterator<Item = Point3<usize>> {
+    let map_size = map.len();
+    return Point3::FACES.into_iter()
+        .filter_map(move |offset| cube.checked_add_signed(offset))
+        .filter(move |neighbor| neighbor.x < map_size && neighbor.y < map_size && neighbor.z < map_size);
+}
+
+fn get_lava_surface_area_recursive(map: &mut Map, cube: Point3<usize>) -> usize {
+    let mut exposed_surface = 6;
+    map[cube.z][cube.y][cube.x] = MapTileState::Visited;
+    for neighbor in neighbors(map, cube) {
+        if tile(map, neighbor) != MapTileState::Air {
+            exposed_surface -= 1;
+            if tile(map, neighbor) != MapTileState::Visited {
+                exposed_surface += get_lava_surface_area_recursive(map, neighbor);
+            }
+        }
+    }
+    return exposed_surface;
+}
+
+fn get_lava_surface_area(map: &mut Map) -> usize {
+    // Find the lava
+    let mut surface = 0;
+    let map_size = map.len();
+    for z in 0..map_size {
+        for y in 0..map_size {
+            for x in 0..map_size {
+                let cube = Point3::new(x, y, z);
+                if tile(map, cube) == MapTileState::Lava {
+                    surface += get_lava_surface_area_recursive(map, cube);
+                }
+            }
+        }
+    }
+    // If no lava then surface area is 0
+    return surface;
+}
+
+fn get_lava_exterior_surface_area_recursive(map: &mut Map, cube: Point3<usize>) -> usize {
+    // Check if any neighbor is lava if it is then add 1 to result
+    // If a neighbor is Air then visit them
+    let mut lava_area = 0;
+    map[cube.z][cube.y][cube.x] = MapTileState::Visited;
+    for neighbor in neighbors(map, cube) {
+        if tile(map, neighbor) == MapTileState::Lava {
+            lava_area += 1;
+        } else if tile(map, neighbor) == MapTileState::Air {
+            lava_area += get_lava_exterior_surface_area_recursive(map, neighbor);
+        }
+    }
+    return lava_area;
+}
+
+fn get_lava_exterior_surface_area(
+    map: &mut Map,
+) -> usize {
+    // We know that map[0][0][0] is air because this is how we designed our map
+    return get_lava_exterior_surface_area_recursive(map, Point3::new(0, 0, 0));
+}
+
+fn solve_problem_1(cubes: &[Point3<usize>], map_size: usize) -> Answer {
+    let mut map = build_map(cubes, map_size);
+    let ans = get_lava_surface_area(&mut map);
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(cubes: &[Point3<usize>], map_size: usize) -> Answer {
+    let mut map = build_map(cubes, map_size);
+    let ans = get_lava_exterior_surface_area(&mut map);
+    return Answer::from(ans);
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Point3<usize>>;
+
+    fn params(&self) -> Vec<Param> {
+        return vec![Param::new::<usize>("size", "Size of the map, larger than the highest coordinate + 2", "25")];
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, params: &Params) -> Answer {
+        return solve_problem_1(input, params.get("size"));
+    }
+
+    fn part2(&self, input: &Self::Input, params: &Params) -> Answer {
+        return solve_problem_2(input, params.get("size"));
+    }
+}
@@ -1,6 +1,4 @@
-use std::fs::{File};
-use std::io::{BufReader, BufRead, Lines};
-use clap::{Parser};
+use aoc_core::{parse, Answer, Params, ParseError, Solution};
 
 const LOSING_SCORE: i32 = 0;
 const DRAWING_SCORE: i32 = 3;
@@ -10,25 +8,8 @@ const ROCK_SCORE: i32 = 1;
 const PAPER_SCORE: i32 = 2;
 const SCISSORS_SCORE: i32 = 3;
 
-#[derive(Debug, Parser)]
-struct Arg {
-    /// Challenge to run (1 or 2)
-    challenge_num: u8,
-
-    /// Input file
-    filename: String,
-}
-
-fn read_lines(filename: &str) -> Lines<BufReader<File>> {
-    let file = match File::open(filename) {
-        Ok(f) => { f },
-        Err(e) => { panic!("[ERROR] {}", e) }
-    };
-    BufReader::new(file).lines()
-}
-
 #[derive(Debug, Clone, Copy, PartialEq)]
-enum Sign {
+pub enum Sign {
     Rock,
     Paper,
     Scissors,
@@ -130,50 +111,68 @@ impl Outcome {
     }
 }
 
-fn solve_problem_1(filename: &str) {
-    let mut ans = 0;
+/// Opponent sign of each round, along with the second column whose meaning
+/// depends on the challenge
+fn parse_input(input: &str) -> Result<Vec<(Sign, char)>, ParseError> {
+    let mut rounds = Vec::new();
 
-    // Open file and read line by line
-    let lines = read_lines(filename);
-    for line in lines {
-        if let Ok(s) = line {
-            if s.len() == 3 {
-                let opponent_sign = Sign::from(s.chars().nth(0).unwrap());
-                let my_sign = Sign::from(s.chars().nth(2).unwrap());
-                let score = my_sign.get_score() + my_sign.against(opponent_sign).get_score();
-                ans += score;
-            }
+    // Read input line by line
+    for s in input.lines() {
+        if s.is_empty() {
+            continue;
+        }
+        let (opponent, column) = parse::split_once(s, " ")?;
+        let opponent_sign = match opponent {
+            "A" | "B" | "C" => { Sign::from(opponent.chars().next().unwrap()) },
+            _ => { return Err(ParseError::new(opponent, "Expected A, B or C")); }
+        };
+        match column {
+            "X" | "Y" | "Z" => { rounds.push((opponent_sign, column.chars().next().unwrap())); },
+            _ => { return Err(ParseError::new(column, "Expected X, Y or Z")); }
         }
     }
 
-    println!("Answer: {:?}", ans);
+    return Ok(rounds);
 }
 
-fn solve_problem_2(filename: &str) {
+fn solve_problem_1(rounds: &[(Sign, char)]) -> Answer {
     let mut ans = 0;
 
-    // Open file and read line by line
-    let lines = read_lines(filename);
-    for line in lines {
-        if let Ok(s) = line {
-            if s.len() == 3 {
-                let opponent_sign = Sign::from(s.chars().nth(0).unwrap());
-                let outcome = Outcome::from(s.chars().nth(2).unwrap());
-                let score = outcome.get_score() + outcome.against(opponent_sign).get_score();
-                ans += score;
-            }
-        }
+    for &(opponent_sign, column) in rounds.iter() {
+        let my_sign = Sign::from(column);
+        let score = my_sign.get_score() + my_sign.against(opponent_sign).get_score();
+        ans += score;
     }
 
-    println!("Answer: {:?}", ans);
+    return Answer::from(ans);
 }
 
-fn main() {
-    let arg = Arg::parse();
+fn solve_problem_2(rounds: &[(Sign, char)]) -> Answer {
+    let mut ans = 0;
+
+    for &(opponent_sign, column) in rounds.iter() {
+        let outcome = Outcome::from(column);
+        let score = outcome.get_score() + outcome.against(opponent_sign).get_score();
+        ans += score;
+    }
+
+    return Answer::from(ans);
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(Sign, char)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
 
-    match arg.challenge_num {
-        1 => { solve_problem_1(&arg.filename); },
-        2 => { solve_problem_2(&arg.filename); },
-        n => { panic!("[ERROR] Incorrect challenge number {}", n); }
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
     }
 }
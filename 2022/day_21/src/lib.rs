@@ -1,19 +1,10 @@
-use clap::Parser;
+use aoc_core::{parse, scan, Answer, Params, ParseError, Solution};
 use std::collections::HashMap;
-use std::fs;
+use std::str::FromStr;
 
-type MonkeyMap = HashMap<String, Monkey>;
+pub type MonkeyMap = HashMap<String, Monkey>;
 
-#[derive(Debug, Parser)]
-struct Arg {
-    /// Challenge to run (1 or 2)
-    challenge_num: u8,
-
-    /// Input file
-    filename: String,
-}
-
-#[derive(PartialEq)]
+#[derive(Clone, PartialEq)]
 enum Operator {
     Integer(i64),
     Plus,
@@ -24,19 +15,22 @@ enum Operator {
     Equals,
 }
 
-impl From<&str> for Operator {
-    fn from(s: &str) -> Self {
+impl FromStr for Operator {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "+" => Self::Plus,
-            "-" => Self::Minus,
-            "*" => Self::Multiply,
-            "/" => Self::Divide,
-            _ => Self::Unknown, // Should never happen
+            "+" => { Ok(Self::Plus) },
+            "-" => { Ok(Self::Minus) },
+            "*" => { Ok(Self::Multiply) },
+            "/" => { Ok(Self::Divide) },
+            _ => { Err(ParseError::new(s, format!("Unknown operator '{}'", s))) },
         }
     }
 }
 
-struct Monkey {
+#[derive(Clone)]
+pub struct Monkey {
     left: String,
     right: String,
     operator: Operator,
@@ -154,39 +148,46 @@ impl Monkey {
     }
 }
 
-impl From<&str> for Monkey {
-    fn from(s: &str) -> Self {
-        let value = s.parse::<i64>();
-        match value {
-            Ok(num) => Self {
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(num) = s.parse::<i64>() {
+            return Ok(Self {
                 left: String::from(""),
                 right: String::from(""),
                 operator: Operator::Integer(num),
-            },
-            Err(_) => {
-                let split = s.split(" ").collect::<Vec<&str>>();
-                Self {
-                    left: String::from(split[0]),
-                    right: String::from(split[2]),
-                    operator: Operator::from(split[1]),
-                }
-            }
+            });
         }
+
+        let (left, operator, right) = scan!(s, "{} {} {}", String, Operator, String)?;
+        return Ok(Self { left, right, operator });
     }
 }
 
-fn parse_input(filename: &str) -> MonkeyMap {
-    let content: String = fs::read_to_string(filename).unwrap();
+fn parse_input(input: &str) -> Result<MonkeyMap, ParseError> {
     let mut map = MonkeyMap::new();
+    let mut references = Vec::new();
 
-    for line in content.lines() {
-        let split = line.split(": ").collect::<Vec<&str>>();
-        let name = String::from(split[0]);
-        let monkey = Monkey::from(split[1]);
-        map.insert(name, monkey);
+    for line in input.lines() {
+        let (name, job) = parse::split_once(line, ": ")?;
+        let monkey = job.parse::<Monkey>()?;
+        if !matches!(monkey.operator, Operator::Integer(_)) {
+            references.extend(job.split(' ').step_by(2));
+        }
+        map.insert(String::from(name), monkey);
     }
 
-    return map;
+    for name in references {
+        if !map.contains_key(name) {
+            return Err(ParseError::new(name, format!("Unknown monkey '{}'", name)));
+        }
+    }
+    if !map.contains_key("root") || !map.contains_key("humn") {
+        return Err(ParseError::new(input, "Expected monkeys named 'root' and 'humn'"));
+    }
+
+    return Ok(map);
 }
 
 fn modify_map(map: &mut MonkeyMap) {
@@ -201,32 +202,33 @@ fn modify_map(map: &mut MonkeyMap) {
     map.insert(s_humn, humn);
 }
 
-fn solve_problem_1(filename: &str) {
-    let map = parse_input(filename);
-    let ans = map.get(&String::from("root")).unwrap().solve(&map);
-    println!("Answer: {:?}", ans);
+fn solve_problem_1(map: &MonkeyMap) -> Answer {
+    let ans = map.get(&String::from("root")).unwrap().solve(map);
+    return Answer::from(ans);
 }
 
-fn solve_problem_2(filename: &str) {
-    let mut map = parse_input(filename);
+fn solve_problem_2(map: &MonkeyMap) -> Answer {
+    let mut map = map.clone();
     modify_map(&mut map);
     let root = map.get(&String::from("root")).unwrap();
     let ans = root.solve_for_x(&map);
-    println!("Answer: {:?}", ans);
+    return Answer::from(ans);
 }
 
-fn main() {
-    let arg = Arg::parse();
+pub struct Day21;
 
-    match arg.challenge_num {
-        1 => {
-            solve_problem_1(&arg.filename);
-        }
-        2 => {
-            solve_problem_2(&arg.filename);
-        }
-        n => {
-            panic!("[ERROR] Incorrect challenge number {}", n);
-        }
+impl Solution for Day21 {
+    type Input = MonkeyMap;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
     }
 }
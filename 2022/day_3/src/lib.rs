@@ -0,0 +1,103 @@
+use aoc_core::{Answer, Params, ParseError, Solution};
+use std::collections::HashMap;
+
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut rucksacks = Vec::new();
+
+    // Read input line by line
+    for s in input.lines() {
+        if let Some((index, c)) = s.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(ParseError::new(&s[index..index + c.len_utf8()], "Items are letters"));
+        }
+        if s.len() % 2 != 0 {
+            return Err(ParseError::new(s, "Both compartments must hold the same number of items"));
+        }
+        rucksacks.push(String::from(s));
+    }
+
+    return Ok(rucksacks);
+}
+
+fn solve_problem_1(rucksacks: &[String]) -> Answer {
+    let mut ans = 0;
+
+    for s in rucksacks.iter() {
+        let (l_comp, r_comp) = s.split_at(s.len() / 2);
+        let mut items_map = HashMap::new();
+        for item in l_comp.chars() {
+            items_map.insert(item, true);
+        }
+        for item in r_comp.chars() {
+            if items_map.contains_key(&item) {
+                if item.is_ascii_uppercase() {
+                    ans += item.to_ascii_uppercase() as i32 - 'A' as i32 + 27;
+                } else {
+                    ans += item.to_ascii_lowercase() as i32 - 'a' as i32 + 1;
+                }
+                break;
+            }
+        }
+    }
+
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(rucksacks: &[String]) -> Answer {
+    let mut ans = 0;
+
+    let mut group: [&str; 3] = Default::default();
+
+    for (line_cnt, s) in rucksacks.iter().enumerate() {
+        group[line_cnt%3] = s;
+
+        // Group is full
+        if line_cnt % 3 == 2 {
+            let mut items_map: HashMap<char, u8> = HashMap::new();
+            // Fill the map with what the first elf owns
+            for item in group[0].chars() {
+                items_map.insert(item, 1);
+            }
+
+            // If elf 2 owns an item that's in the map then pass the count to 2
+            for item in group[1].chars() {
+                items_map.entry(item).and_modify(|count| *count = 2);
+            }
+
+            // If an item is owned by elf 3 and the other 2 elves then it is the badge
+            for item in group[2].chars() {
+                let value = match items_map.get(&item) {
+                    Some(&v) => { v },
+                    None => { 0 }
+                };
+                if value == 2 {
+                    if item.is_ascii_uppercase() {
+                        ans += item.to_ascii_uppercase() as i32 - 'A' as i32 + 27;
+                    } else {
+                        ans += item.to_ascii_lowercase() as i32 - 'a' as i32 + 1;
+                    }
+                    break;
+                }
+            }
+        }
+    }
+
+    return Answer::from(ans);
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
+    }
+}
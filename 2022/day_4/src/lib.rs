@@ -0,0 +1,56 @@
+use aoc_core::{parse, Answer, Interval, Params, ParseError, Solution};
+use std::str::FromStr;
+
+fn parse_input(input: &str) -> Result<Vec<(Interval, Interval)>, ParseError> {
+    let mut pairs = Vec::new();
+
+    // Read input line by line
+    for s in input.lines() {
+        let (section_1, section_2) = parse::split_once(s, ",")?;
+        pairs.push((Interval::from_str(section_1)?, Interval::from_str(section_2)?));
+    }
+
+    return Ok(pairs);
+}
+
+fn solve_problem_1(pairs: &[(Interval, Interval)]) -> Answer {
+    let mut ans = 0;
+
+    for &(section_1, section_2) in pairs.iter() {
+        if section_1.covers(section_2) || section_2.covers(section_1) {
+            ans += 1;
+        }
+    }
+
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(pairs: &[(Interval, Interval)]) -> Answer {
+    let mut ans = 0;
+
+    for &(section_1, section_2) in pairs.iter() {
+        if section_1.overlaps(section_2) {
+            ans += 1;
+        }
+    }
+
+    return Answer::from(ans);
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<(Interval, Interval)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
+    }
+}
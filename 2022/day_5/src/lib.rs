@@ -0,0 +1,161 @@
+use aoc_core::{input, scan, Answer, Params, ParseError, Solution};
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone)]
+enum CraneModel {
+    CrateMover9000,
+    CrateMover9001,
+}
+
+#[derive(Debug, Clone)]
+pub struct CrateStorage {
+    stacks: Vec<Vec<char>>,
+}
+
+impl FromStr for CrateStorage {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let v = s.lines().collect::<Vec<&str>>();
+        // Get the number of stacks
+        let count = match v.first() {
+            Some(s) => { (s.len() + 1) / 4 },
+            None => { return Err(ParseError::new(s, "Empty crate storage")); }
+        };
+        // Initialize the stacks
+        let mut stacks: Vec<Vec<char>> = Vec::new();
+        for _ in 0..count {
+            stacks.push(Vec::new());
+        }
+
+        // Parse each line in reverse order to get the stacks right
+        for s in v.iter().rev() {
+            for stack_index in 0..count {
+                let character = match s.chars().nth(stack_index * 4 + 1) {
+                    Some(character) => { character },
+                    None => { return Err(ParseError::new(s, format!("Expected {} stacks", count))); }
+                };
+                if character.is_numeric() {
+                    // This is the last line
+                    break;
+                } else if character != ' ' {
+                    stacks[stack_index].push(character);
+                }
+            }
+        }
+
+        Ok(Self {
+            stacks,
+        })
+    }
+}
+
+impl CrateStorage {
+    fn use_crane(&mut self, crane_model: CraneModel, move_to_use: Move) {
+        match crane_model {
+            CraneModel::CrateMover9000 => {
+                for _ in 0..move_to_use.amount {
+                    let value = self.stacks[move_to_use.from - 1].pop().unwrap_or(' ');
+                    if value != ' ' {
+                        self.stacks[move_to_use.to - 1].push(value);
+                    }
+                }
+            },
+            CraneModel::CrateMover9001 => {
+                let mut tmp = Vec::new();
+                for _ in 0..move_to_use.amount {
+                    let value = self.stacks[move_to_use.from - 1].pop().unwrap_or(' ');
+                    tmp.push(value);
+                }
+
+                for character in tmp.into_iter().rev() {
+                    self.stacks[move_to_use.to - 1].push(character);
+                }
+            }
+        }
+    }
+
+    fn get_top(&self) -> String {
+        self.stacks.iter()
+            .map(|s| match s.last() {
+                Some(value) => { return *value; },
+                None => { return ' '; }
+            })
+            .collect::<String>()
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Move {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (amount, from, to) = scan!(s, "move {} from {} to {}", usize, usize, usize)?;
+        Ok(Self { amount, from, to })
+    }
+}
+
+fn parse_input(input: &str) -> Result<(CrateStorage, Vec<Move>), ParseError> {
+    let blocks = input::paragraphs(input);
+    if blocks.len() != 2 {
+        return Err(ParseError::new(input, format!("Expected the crate storage and the moves, got {} blocks", blocks.len())));
+    }
+
+    let crate_storage = CrateStorage::from_str(blocks[0])?;
+    let mut moves = Vec::new();
+    for s in blocks[1].lines() {
+        let next_move = Move::from_str(s)?;
+        let count = crate_storage.stacks.len();
+        if next_move.from == 0 || next_move.from > count || next_move.to == 0 || next_move.to > count {
+            return Err(ParseError::new(s, format!("Stacks are numbered from 1 to {}", count)));
+        }
+        moves.push(next_move);
+    }
+    return Ok((crate_storage, moves));
+}
+
+fn solve_problem_1(crate_storage: &CrateStorage, moves: &[Move]) -> Answer {
+    let mut crate_storage = crate_storage.clone();
+
+    for current_move in moves.iter() {
+        crate_storage.use_crane(CraneModel::CrateMover9000, *current_move);
+    }
+
+    let ans = crate_storage.get_top();
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(crate_storage: &CrateStorage, moves: &[Move]) -> Answer {
+    let mut crate_storage = crate_storage.clone();
+
+    for current_move in moves.iter() {
+        crate_storage.use_crane(CraneModel::CrateMover9001, *current_move);
+    }
+
+    let ans = crate_storage.get_top();
+    return Answer::from(ans);
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (CrateStorage, Vec<Move>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(&input.0, &input.1);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(&input.0, &input.1);
+    }
+}
@@ -0,0 +1,26 @@
+#!/bin/bash
+
+set -e
+
+LRED='\033[1;31m'   # Light Red
+LGREEN='\033[1;32m' # Light Green
+LCYAN='\033[1;36m'  # Light Cyan
+NC='\033[0m' # No Color
+
+dir_name=${PWD##*/}
+# dir_name=${result:-/}
+
+dir_name=$(echo "${dir_name}" | sed 's/_/ /g')
+
+# Every day is run through the workspace runner
+year=$(basename "$(dirname "${PWD}")")
+day_num=${PWD##*_}
+
+printf "${LGREEN}------------------ BUILDING ${dir_name^^} ------------------${NC}\n"
+cargo build --release -p aoc
+printf "${LGREEN}--------------- DONE BUILDING ${dir_name^^} ----------------${NC}\n\n"
+
+printf "${LGREEN}------------- RUNNING ${dir_name^^} TEST CASES -------------${NC}\n"
+# Compares the answers of every input to the ones recorded in answers.toml
+../../target/release/aoc check ${year} ${day_num}
+printf "${LGREEN}----------- DONE RUNNING ${dir_name^^} TEST CASES -----------${NC}\n"
@@ -0,0 +1,59 @@
+use aoc_core::{Answer, Params, ParseError, Solution};
+
+fn find_start_of_message_marker(buffer: &str, start_marker_size: usize) -> i32 {
+    let mut ans: i32 = -1;
+    for i in 0..(buffer.len() - start_marker_size) {
+        let marker = &buffer[i..i + start_marker_size];
+        let mut chars: Vec<char> = marker.chars().collect();
+        chars.sort_by(|a, b| b.cmp(a));
+        chars.dedup();
+        if chars.len() == start_marker_size {
+            ans = (i + start_marker_size) as i32;
+            break;
+        }
+    }
+    return ans;
+}
+
+fn parse_input(input: &str) -> Result<String, ParseError> {
+    if let Some((index, c)) = input.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+        return Err(ParseError::new(&input[index..index + c.len_utf8()], "The datastream only contains lowercase letters"));
+    }
+    return Ok(String::from(input));
+}
+
+fn solve_problem_1(buffer: &str) -> Answer {
+    let ans = find_start_of_message_marker(buffer, 4);
+
+    if ans == -1 {
+        panic!("[ERROR] No start signal");
+    }
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(buffer: &str) -> Answer {
+    let ans = find_start_of_message_marker(buffer, 14);
+
+    if ans == -1 {
+        panic!("[ERROR] No start signal");
+    }
+    return Answer::from(ans);
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
+    }
+}
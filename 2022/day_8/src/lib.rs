@@ -0,0 +1,62 @@
+use aoc_core::grid::{self, Pos};
+use aoc_core::{Answer, Direction, Grid, Params, ParseError, Solution};
+use std::cmp::max;
+
+fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    return Grid::parse(input, grid::digit);
+}
+
+/// Whether a tree can be seen from outside the grid, looking along one of the
+/// rows or columns
+fn is_visible(forest: &Grid<u32>, tree: Pos) -> bool {
+    return Direction::ALL.iter().any(|direction| {
+        forest.ray(tree, direction.offset()).all(|pos| forest[pos] < forest[tree])
+    });
+}
+
+fn solve_problem_1(forest: &Grid<u32>) -> Answer {
+    let ans = forest.positions().filter(|&tree| is_visible(forest, tree)).count();
+    return Answer::from(ans);
+}
+
+/// Number of trees seen from a tree in each direction, multiplied
+fn scenic_score(forest: &Grid<u32>, tree: Pos) -> usize {
+    let mut scenic_score = 1;
+    for direction in Direction::ALL.iter() {
+        let mut seen = 0;
+        for pos in forest.ray(tree, direction.offset()) {
+            seen += 1;
+            if forest[pos] >= forest[tree] {
+                break;
+            }
+        }
+        scenic_score *= seen;
+    }
+    return scenic_score;
+}
+
+fn solve_problem_2(forest: &Grid<u32>) -> Answer {
+    let mut ans = 0;
+    for tree in forest.positions() {
+        ans = max(ans, scenic_score(forest, tree));
+    }
+    return Answer::from(ans);
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_1(input);
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Answer {
+        return solve_problem_2(input);
+    }
+}
@@ -0,0 +1,156 @@
+use aoc_core::visualize::{self, Cell, Color};
+use aoc_core::{parse, Answer, Direction, Frame, Param, Params, ParseError, Point2, Solution};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Movement {
+    amount: i32,
+    direction: Direction,
+}
+
+impl fmt::Display for Movement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let letter = match self.direction {
+            Direction::Up => { 'U' },
+            Direction::Down => { 'D' },
+            Direction::Left => { 'L' },
+            Direction::Right => { 'R' },
+        };
+        return write!(f, "{} {}", letter, self.amount);
+    }
+}
+
+impl FromStr for Movement {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, amount) = parse::split_once(s, " ")?;
+        let amount = parse::number::<i32>(amount)?;
+
+        let direction = match direction {
+            "U" => { Direction::Up },
+            "D" => { Direction::Down },
+            "L" => { Direction::Left },
+            "R" => { Direction::Right },
+            other => { return Err(ParseError::new(other, format!("{} is not a valid direction indicator", other))); }
+        };
+
+        Ok(Self {
+            amount,
+            direction,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Rope {
+    knots: Vec<Point2<i32>>,
+}
+
+impl Rope {
+    fn new(knot_count: usize) -> Self {
+        Self {
+            knots: vec![Point2::new(0, 0); knot_count],
+        }
+    }
+
+    /// Knots of the rope over the positions visited by its tail
+    fn draw(&self, visited: &HashMap<Point2<i32>, bool>) -> Frame {
+        let mut frame = Frame::new();
+        for pos in visited.keys() {
+            frame.plot(pos.x as i64, pos.y as i64, Cell::colored('#', Color::Gray));
+        }
+        // The knots closest to the head are drawn over the others
+        for (i, knot) in self.knots.iter().enumerate().rev() {
+            let symbol = if i == 0 {
+                'H'
+            } else if i == self.knots.len() - 1 {
+                'T'
+            } else {
+                char::from_digit(i as u32 % 10, 10).unwrap()
+            };
+            frame.plot(knot.x as i64, knot.y as i64, Cell::colored(symbol, Color::Yellow));
+        }
+        return frame;
+    }
+
+    fn apply_movement(&mut self, map: &mut HashMap<Point2<i32>, bool>, movement: Movement) {
+        for _ in 0..movement.amount {
+            // Update head position
+            self.knots[0] = self.knots[0].moved(movement.direction);
+            // Update rest of the rope
+            map.entry(self.follow_head()).or_insert(true);
+
+            visualize::frame(|| self.draw(map).titled(format!("== {} ==", movement)));
+        }
+    }
+
+    fn follow_head(&mut self) -> Point2<i32> {
+        for i in 1..self.knots.len() {
+            // A knot that is not touching the previous one moves one step
+            // towards it, diagonally if they are not on the same row or column
+            if self.knots[i].chebyshev(self.knots[i - 1]) > 1 {
+                let step = (self.knots[i - 1] - self.knots[i]).signum();
+                self.knots[i] += step;
+            }
+        }
+        return *self.knots.last().unwrap();
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Movement>, ParseError> {
+    // Read input line by line
+    return input.lines().map(Movement::from_str).collect();
+}
+
+fn solve_problem_1(movements: &[Movement], nb_knots: usize) -> Answer {
+    let mut visited: HashMap<Point2<i32>, bool> = HashMap::new();
+    let mut rope = Rope::new(nb_knots);
+    visited.insert(*rope.knots.last().unwrap(), true);
+
+    for &movement in movements.iter() {
+        rope.apply_movement(&mut visited, movement);
+    }
+
+    let ans = visited.len();
+    return Answer::from(ans);
+}
+
+fn solve_problem_2(movements: &[Movement], nb_knots: usize) -> Answer {
+    let mut visited: HashMap<Point2<i32>, bool> = HashMap::new();
+    let mut rope = Rope::new(nb_knots);
+    visited.insert(*rope.knots.last().unwrap(), true);
+
+    visualize::frame(|| rope.draw(&visited).titled("== Initial State =="));
+
+    for &movement in movements.iter() {
+        rope.apply_movement(&mut visited, movement);
+    }
+
+    let ans = visited.len();
+    return Answer::from(ans);
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Movement>;
+
+    fn params(&self) -> Vec<Param> {
+        return vec![Param::per_part::<usize>("knots", "Number of knots of the rope", "2", "10").at_least(1)];
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1(&self, input: &Self::Input, params: &Params) -> Answer {
+        return solve_problem_1(input, params.get("knots"));
+    }
+
+    fn part2(&self, input: &Self::Input, params: &Params) -> Answer {
+        return solve_problem_2(input, params.get("knots"));
+    }
+}
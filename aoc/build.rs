@@ -0,0 +1,62 @@
+//! Generates a test for every part of every control input having a recorded
+//! expected answer (see `aoc_core::expected`)
+
+use aoc_core::expected;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let root = expected::repository_root();
+    let mut tests = String::new();
+
+    let mut day_dirs = Vec::new();
+    for year_entry in fs::read_dir(&root).unwrap().flatten() {
+        let year = match year_entry.file_name().to_string_lossy().parse::<u16>() {
+            Ok(year) => { year },
+            Err(_) => { continue; }
+        };
+        for day_entry in fs::read_dir(year_entry.path()).unwrap().flatten() {
+            let name = day_entry.file_name().to_string_lossy().to_string();
+            if let Some(Ok(day)) = name.strip_prefix("day_").map(|s| s.parse::<u8>()) {
+                day_dirs.push((year, day));
+            }
+        }
+    }
+    day_dirs.sort();
+
+    for (year, day) in day_dirs {
+        let dir = expected::day_dir(year, day);
+        // Solutions which are not part of the workspace (e.g. C ones) can not be tested
+        if !dir.join("Cargo.toml").is_file() {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", dir.display());
+
+        let answers = expected::load(&dir);
+        for path in expected::day_inputs(&dir) {
+            let name = path.file_name().unwrap().to_string_lossy().to_string();
+            if !name.starts_with("control_input") {
+                continue;
+            }
+            let recorded = match answers.get(&name) {
+                Some(recorded) => { recorded },
+                None => { continue; }
+            };
+            for challenge_num in 1..=2 {
+                if recorded.get_answer(challenge_num).is_none() {
+                    continue;
+                }
+                let test_name = format!("y{}_day_{}_{}_part_{}", year, day, name.trim_end_matches(".txt"), challenge_num);
+                writeln!(tests, "#[test]").unwrap();
+                writeln!(tests, "fn {}() {{", test_name).unwrap();
+                writeln!(tests, "    assert_control({}, {}, {:?}, {});", year, day, name, challenge_num).unwrap();
+                writeln!(tests, "}}").unwrap();
+            }
+        }
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("control_tests.rs"), tests).unwrap();
+}
@@ -0,0 +1,249 @@
+use aoc_core::cli;
+use aoc_core::{expected, input, Params, SolveError};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::time::Duration;
+
+use crate::registry::{self, Entry};
+
+/// Distribution of the durations of a step, in nanoseconds
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub p95: u64,
+}
+
+impl Stats {
+    fn from_durations(durations: &[Duration]) -> Self {
+        let mut nanos = durations.iter().map(|d| d.as_nanos() as u64).collect::<Vec<u64>>();
+        nanos.sort_unstable();
+        let p95_index = (nanos.len() * 95).div_ceil(100).max(1) - 1;
+        Self {
+            min: nanos[0],
+            median: nanos[nanos.len() / 2],
+            p95: nanos[p95_index],
+        }
+    }
+}
+
+/// Timings of a single challenge
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Measure {
+    pub year: u16,
+    pub day: u8,
+    pub challenge_num: u8,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Measure {
+    fn get_median(&self) -> u64 {
+        return self.parse.median + self.solve.median;
+    }
+
+    /// Change of the median time since the `previous` measure, in percent
+    fn change_since(&self, previous: &Measure) -> f64 {
+        return (self.get_median() as f64 / previous.get_median().max(1) as f64 - 1.0) * 100.0;
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Report {
+    pub runs: usize,
+    pub warmup: usize,
+    pub input: String,
+    pub measures: Vec<Measure>,
+}
+
+fn format_nanos(nanos: u64) -> String {
+    return format!("{:.1?}", Duration::from_nanos(nanos));
+}
+
+/// Timings of a challenge on the benchmarked input of its day, none if the
+/// day has no such input, or why it could not be measured
+fn measure(entry: &Entry, challenge_num: u8, args: &cli::Bench) -> Result<Option<Measure>, String> {
+    let dir = expected::day_dir(entry.year, entry.day);
+    let path = dir.join(&args.input);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let content = input::load(&path.to_string_lossy());
+    let solution = (entry.build)();
+    let params = match Params::resolve(&solution.params(), challenge_num, &[&expected::overrides_for(&path)]) {
+        Ok(params) => { params },
+        Err(e) => { return Err(format!("[ERROR] {}: {}", path.display(), e)); }
+    };
+    // Inputs that do not parse (or give no answer) are left out of the
+    // report, the first run (warmup or measured) telling
+    let solve_error = |e: SolveError| e.with_file(&path.to_string_lossy()).to_string();
+    for _ in 0..args.warmup {
+        if let Err(e) = solution.solve(challenge_num, &content, &params) {
+            return Err(solve_error(e));
+        }
+    }
+
+    let mut parse_times = Vec::new();
+    let mut solve_times = Vec::new();
+    for _ in 0..args.runs {
+        let timed = match solution.solve_timed(challenge_num, &content, &params) {
+            Ok(timed) => { timed },
+            Err(e) => { return Err(solve_error(e)); }
+        };
+        parse_times.push(timed.parse_time);
+        solve_times.push(timed.solve_time);
+    }
+
+    return Ok(Some(Measure {
+        year: entry.year,
+        day: entry.day,
+        challenge_num,
+        parse: Stats::from_durations(&parse_times),
+        solve: Stats::from_durations(&solve_times),
+    }));
+}
+
+fn load_report(filename: &str) -> Report {
+    let content = input::load(filename);
+    match serde_json::from_str(&content) {
+        Ok(report) => { report },
+        Err(e) => { panic!("[ERROR] {}: {}", filename, e); }
+    }
+}
+
+/// Measures of the baseline having no counterpart in the report
+fn missing_from<'a>(baseline: &'a Report, report: &Report) -> Vec<&'a Measure> {
+    return baseline.measures.iter()
+        .filter(|previous| !report.measures.iter().any(|current| {
+            current.year == previous.year && current.day == previous.day && current.challenge_num == previous.challenge_num
+        }))
+        .collect();
+}
+
+/// Benchmark the selected challenges and print their timings
+///
+/// Returns false if a challenge could not be measured, got slower than in the
+/// baseline or is missing from the measures while the baseline has it.
+pub fn run(args: &cli::Bench) -> bool {
+    if args.runs == 0 {
+        panic!("[ERROR] At least one run is needed");
+    }
+    let baseline = args.baseline.as_deref().map(load_report);
+
+    let mut report = Report {
+        runs: args.runs,
+        warmup: args.warmup,
+        input: args.input.clone(),
+        measures: Vec::new(),
+    };
+    let mut regression = false;
+    let mut unmeasured = false;
+
+    println!(
+        "{:<20} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10}",
+        "challenge", "parse min", "median", "p95", "solve min", "median", "p95"
+    );
+    for entry in registry::SOLUTIONS.iter() {
+        if args.year.is_some_and(|year| year != entry.year) || args.day.is_some_and(|day| day != entry.day) {
+            continue;
+        }
+        for challenge_num in 1..=2 {
+            let current = match measure(entry, challenge_num, args) {
+                Ok(Some(current)) => { current },
+                Ok(None) => { continue; },
+                Err(e) => {
+                    eprintln!("{}", e);
+                    unmeasured = true;
+                    continue;
+                }
+            };
+            let name = format!("{} day {} part {}", current.year, current.day, current.challenge_num);
+            let mut line = format!(
+                "{:<20} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10}",
+                name,
+                format_nanos(current.parse.min),
+                format_nanos(current.parse.median),
+                format_nanos(current.parse.p95),
+                format_nanos(current.solve.min),
+                format_nanos(current.solve.median),
+                format_nanos(current.solve.p95),
+            );
+
+            let previous = baseline.as_ref().and_then(|baseline| baseline.measures.iter().find(|m| {
+                m.year == current.year && m.day == current.day && m.challenge_num == current.challenge_num
+            }));
+            if let Some(previous) = previous {
+                let change = current.change_since(previous);
+                line.push_str(&format!(" {:>+8.1}%", change));
+                if change > args.threshold {
+                    line.push_str(" REGRESSION");
+                    regression = true;
+                }
+            }
+            println!("{}", line);
+            report.measures.push(current);
+        }
+    }
+
+    if let Some(baseline) = &baseline {
+        let selected = |m: &Measure| args.year.is_none_or(|year| year == m.year) && args.day.is_none_or(|day| day == m.day);
+        for previous in missing_from(baseline, &report).into_iter().filter(|m| selected(m)) {
+            let name = format!("{} day {} part {}", previous.year, previous.day, previous.challenge_num);
+            println!("{:<20} MISSING (measured in the baseline)", name);
+            regression = true;
+        }
+    }
+
+    if let Some(output) = &args.output {
+        let json = match serde_json::to_string_pretty(&report) {
+            Ok(json) => { json },
+            Err(e) => { panic!("[ERROR] Cannot serialize the report: {}", e); }
+        };
+        if let Err(e) = fs::write(output, json) {
+            panic!("[ERROR] {}: {}", output, e);
+        }
+    }
+
+    return !regression && !unmeasured;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measure(parse_millis: u64, solve_millis: u64) -> Measure {
+        let stats = |millis: u64| Stats::from_durations(&[Duration::from_millis(millis)]);
+        return Measure { year: 2022, day: 1, challenge_num: 1, parse: stats(parse_millis), solve: stats(solve_millis) };
+    }
+
+    #[test]
+    fn computes_stats() {
+        let durations = (1..=20).rev().map(Duration::from_micros).collect::<Vec<Duration>>();
+        let stats = Stats::from_durations(&durations);
+        assert_eq!((stats.min, stats.median, stats.p95), (1_000, 11_000, 19_000));
+
+        let stats = Stats::from_durations(&[Duration::from_nanos(42)]);
+        assert_eq!((stats.min, stats.median, stats.p95), (42, 42, 42));
+    }
+
+    #[test]
+    fn compares_to_baseline() {
+        let baseline = measure(10, 90);
+        let slower = measure(10, 105);
+        assert!((slower.change_since(&baseline) - 15.0).abs() < 1e-9);
+        assert!(slower.change_since(&baseline) > 10.0);
+        assert!(measure(10, 95).change_since(&baseline) < 10.0);
+        assert!(measure(5, 50).change_since(&baseline) < 0.0);
+    }
+
+    #[test]
+    fn finds_missing_measures() {
+        let mut second = measure(10, 90);
+        second.challenge_num = 2;
+        let report = |measures: Vec<Measure>| Report { runs: 1, warmup: 0, input: String::from("input.txt"), measures };
+        let baseline = report(vec![measure(10, 90), second]);
+        let missing = missing_from(&baseline, &report(vec![measure(12, 80)]));
+        assert_eq!(missing.iter().map(|m| m.challenge_num).collect::<Vec<u8>>(), vec![2]);
+        assert!(missing_from(&baseline, &baseline).is_empty());
+    }
+}
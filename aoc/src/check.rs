@@ -0,0 +1,224 @@
+use aoc_core::expected::{self, Expected};
+use aoc_core::{input, Answer, Params};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::registry::Entry;
+
+/// Stack size of the thread running a solution
+const SOLVER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    Pass,
+    Fail,
+    /// No expected answer recorded for this input and part
+    Unknown,
+    /// Not run, as a control input did not pass
+    Skipped,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Pass => { f.pad("PASS") },
+            Self::Fail => { f.pad("FAIL") },
+            Self::Unknown => { f.pad("UNKNOWN") },
+            Self::Skipped => { f.pad("SKIPPED") },
+        }
+    }
+}
+
+/// Outcome of a single part run on a single input
+#[derive(Debug)]
+pub struct Check {
+    pub input: String,
+    pub challenge_num: u8,
+    /// Why no answer was produced: the rendered parse error, or a panic
+    pub answer: Result<Answer, String>,
+    pub expected: Option<String>,
+    pub status: Status,
+    /// Time spent parsing the input and solving the part
+    pub time: Duration,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:<7} {} part {}: ", self.status, self.input, self.challenge_num)?;
+        // Errors (e.g. a parse error pointing at the input) are not compared
+        let answer = match &self.answer {
+            Ok(answer) => { answer.to_string() },
+            Err(reason) => { return write!(f, "{}", reason); },
+        };
+        match (self.status, &self.expected) {
+            (Status::Fail, Some(expected)) if answer.trim().contains('\n') || expected.trim().contains('\n') => {
+                write!(f, "{}", diff(&answer, expected))
+            },
+            (Status::Fail, Some(expected)) => { write!(f, "{} (expected {})", answer, expected.trim()) },
+            _ => { write!(f, "{}", answer) },
+        }
+    }
+}
+
+/// Line by line difference between a multi-line answer (e.g. a screen) and
+/// the expected one, `-` marking the expected lines and `+` the wrong ones
+fn diff(answer: &str, expected: &str) -> String {
+    let answer = answer.trim().lines().collect::<Vec<&str>>();
+    let expected = expected.trim().lines().collect::<Vec<&str>>();
+    let mut diff = String::new();
+    for index in 0..answer.len().max(expected.len()) {
+        match (expected.get(index), answer.get(index)) {
+            (Some(wanted), Some(got)) if wanted == got => { diff.push_str(&format!("\n  {}", got)); },
+            (wanted, got) => {
+                if let Some(wanted) = wanted {
+                    diff.push_str(&format!("\n- {}", wanted));
+                }
+                if let Some(got) = got {
+                    diff.push_str(&format!("\n+ {}", got));
+                }
+            }
+        }
+    }
+    return diff;
+}
+
+/// Run a part of a day on an input and compare the answer to the recorded one
+pub fn check_part(entry: &Entry, path: &Path, recorded: &Expected, challenge_num: u8) -> Check {
+    let name = path.file_name().unwrap().to_string_lossy().to_string();
+    let content = input::load(&path.to_string_lossy());
+    let overrides = recorded.overrides();
+    // Recursive solutions (e.g. 2022 day 18 flood fill) need more stack than
+    // the default one of spawned threads
+    let start = Instant::now();
+    let answer = thread::scope(|scope| {
+        let handle = thread::Builder::new()
+            .stack_size(SOLVER_STACK_SIZE)
+            .spawn_scoped(scope, || {
+                let solution = (entry.build)();
+                let params = Params::resolve(&solution.params(), challenge_num, &[&overrides])?;
+                match solution.solve(challenge_num, &content, &params) {
+                    Ok(answer) => { Ok(answer) },
+                    Err(e) => { Err(format!("\n{}", e.with_file(&path.to_string_lossy()))) },
+                }
+            });
+        match handle {
+            Ok(handle) => { handle.join() },
+            Err(e) => { panic!("[ERROR] Could not spawn solver thread: {}", e); }
+        }
+    });
+    let time = start.elapsed();
+    let answer = match answer {
+        Ok(Ok(answer)) => { Ok(answer) },
+        Ok(Err(reason)) => { Err(reason) },
+        Err(_) => { Err(String::from("panicked")) },
+    };
+    let expected = recorded.get_answer(challenge_num).map(String::from);
+    let status = match (&answer, &expected) {
+        (Err(_), _) => { Status::Fail },
+        (Ok(_), None) => { Status::Unknown },
+        (Ok(answer), Some(expected)) => {
+            if answer.matches(expected) { Status::Pass } else { Status::Fail }
+        },
+    };
+    return Check { input: name, challenge_num, answer, expected, status, time };
+}
+
+/// Run both parts of a day on every input found in its directory and compare
+/// the answers to the recorded ones
+///
+/// When `staged`, the puzzle input is skipped unless every control input
+/// passes.
+pub fn check_day(entry: &Entry, staged: bool) -> Vec<Check> {
+    let mut checks: Vec<Check> = Vec::new();
+    for job in day_jobs(entry).iter() {
+        let name = job.path.file_name().unwrap().to_string_lossy().to_string();
+        let controls_pass = checks.iter()
+            .filter(|check| check.input != "input.txt")
+            .all(|check| check.status == Status::Pass);
+        if staged && name == "input.txt" && !controls_pass {
+            checks.push(Check {
+                input: name,
+                challenge_num: job.challenge_num,
+                answer: Err(String::from("control answers do not match")),
+                expected: job.recorded.get_answer(job.challenge_num).map(String::from),
+                status: Status::Skipped,
+                time: Duration::ZERO,
+            });
+            continue;
+        }
+        checks.push(job.run());
+    }
+    return checks;
+}
+
+/// A part of a day to run on one of its inputs
+#[derive(Debug)]
+pub struct Job<'a> {
+    pub entry: &'a Entry,
+    pub path: PathBuf,
+    pub recorded: Expected,
+    pub challenge_num: u8,
+}
+
+impl Job<'_> {
+    pub fn run(&self) -> Check {
+        return check_part(self.entry, &self.path, &self.recorded, self.challenge_num);
+    }
+}
+
+/// Both parts of a day on every input found in its directory
+pub fn day_jobs(entry: &Entry) -> Vec<Job<'_>> {
+    let dir = expected::day_dir(entry.year, entry.day);
+    let answers = expected::load(&dir);
+    let mut jobs = Vec::new();
+
+    for path in expected::day_inputs(&dir) {
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        let recorded = answers.get(&name).cloned().unwrap_or_default();
+        for challenge_num in 1..=2 {
+            jobs.push(Job { entry, path: path.clone(), recorded: recorded.clone(), challenge_num });
+        }
+    }
+
+    return jobs;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use std::fs;
+
+    /// Check a control input having a recorded answer, called by the tests
+    /// generated by the build script
+    fn assert_control(year: u16, day: u8, name: &str, challenge_num: u8) {
+        let entry = registry::find(year, day).unwrap();
+        let dir = expected::day_dir(year, day);
+        let answers = expected::load(&dir);
+        let check = check_part(entry, &dir.join(name), &answers[name], challenge_num);
+        assert_eq!(check.status, Status::Pass, "{} day {}: {}", year, day, check);
+    }
+
+    #[test]
+    fn fails_on_unreadable_letters() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.txt");
+        // The sprite never moves, drawing a block the size of a letter
+        fs::write(&path, "noop\n".repeat(240)).unwrap();
+        let recorded = Expected { part2: Some(String::from("EZFCHJAB")), ..Expected::default() };
+        let check = check_part(registry::find(2022, 10).unwrap(), &path, &recorded, 2);
+        assert_eq!(check.status, Status::Fail);
+        assert!(check.to_string().starts_with("FAIL    input.txt part 2: \n[OCR ERROR] Unknown glyph at column 0\n###"));
+    }
+
+    #[test]
+    fn diffs_screens_line_by_line() {
+        assert_eq!(diff("\n#.\n.#", "#.\n##\n..\n"), "\n  #.\n- ##\n+ .#\n- ..");
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/control_tests.rs"));
+}
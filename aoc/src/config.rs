@@ -0,0 +1,96 @@
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Environment variable overriding the location of the configuration file
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+
+/// Name of the runner in the user agent of the requests to the website
+pub const USER_AGENT: &str = "aoc solutions runner";
+
+/// Address of the Advent of Code website
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings of the commands talking to the Advent of Code website, read from
+/// `config.toml` in the per-user configuration directory (e.g.
+/// `~/.config/aoc/config.toml`)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in browser
+    pub session: Option<String>,
+    /// Email address or repository URL sent in the user agent, so that the
+    /// website maintainers can reach the user of the runner
+    pub contact: Option<String>,
+    /// Address of the website, to talk to a local stand-in instead
+    pub base_url: Option<String>,
+    /// Directory where downloaded inputs are kept
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl Config {
+    /// Path of the configuration file
+    pub fn path() -> PathBuf {
+        if let Some(path) = env::var_os(CONFIG_ENV) {
+            return PathBuf::from(path);
+        }
+        match dirs::config_dir() {
+            Some(dir) => { dir.join("aoc").join("config.toml") },
+            None => { panic!("[ERROR] No configuration directory, set {}", CONFIG_ENV); }
+        }
+    }
+
+    /// Read the configuration file, a missing file gives the default settings
+    pub fn load() -> Config {
+        let path = Config::path();
+        if !path.is_file() {
+            return Config::default();
+        }
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => { content },
+            Err(e) => { panic!("[ERROR] {}: {}", path.display(), e); }
+        };
+        match toml::from_str(&content) {
+            Ok(config) => { config },
+            Err(e) => { panic!("[ERROR] {}: {}", path.display(), e); }
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        return self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL).trim_end_matches('/');
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        match self.session.as_deref() {
+            Some(session) => { Ok(session.trim()) },
+            None => {
+                Err(format!("No session token, add `session = \"...\"` to {}", Config::path().display()))
+            }
+        }
+    }
+
+    /// User agent of the requests to the website, naming the runner and how
+    /// to contact its user as requested by the website maintainers
+    pub fn user_agent(&self) -> Result<String, String> {
+        match self.contact.as_deref().map(str::trim) {
+            Some(contact) if !contact.is_empty() => { Ok(format!("{} (+{})", USER_AGENT, contact)) },
+            _ => {
+                Err(format!(
+                    "No contact for the user agent, add `contact = \"...\"` (an email address or repository URL) to {}",
+                    Config::path().display()
+                ))
+            }
+        }
+    }
+
+    pub fn cache_dir(&self) -> PathBuf {
+        if let Some(dir) = &self.cache_dir {
+            return dir.clone();
+        }
+        match dirs::cache_dir() {
+            Some(dir) => { dir.join("aoc") },
+            None => { panic!("[ERROR] No cache directory, set cache_dir in {}", Config::path().display()); }
+        }
+    }
+}
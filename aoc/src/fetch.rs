@@ -0,0 +1,178 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+
+/// Where the input was read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    Cache,
+    Download,
+}
+
+/// Path of the cached input of a day
+pub fn cache_path(config: &Config, year: u16, day: u8) -> PathBuf {
+    return config.cache_dir().join("inputs").join(year.to_string()).join(format!("day_{}.txt", day));
+}
+
+fn download(config: &Config, year: u16, day: u8) -> Result<String, String> {
+    let url = format!("{}/{}/day/{}/input", config.base_url(), year, day);
+    let response = ureq::get(&url)
+        .set("User-Agent", &config.user_agent()?)
+        .set("Cookie", &format!("session={}", config.session()?))
+        .call();
+    match response {
+        Ok(response) => {
+            match response.into_string() {
+                Ok(content) => { Ok(content) },
+                Err(e) => { Err(format!("{}: {}", url, e)) },
+            }
+        },
+        Err(ureq::Error::Status(code, _)) => {
+            Err(format!("{}: status {} (is the session token valid and the puzzle unlocked?)", url, code))
+        },
+        Err(e) => { Err(format!("{}: {}", url, e)) },
+    }
+}
+
+/// Get the input of a day, downloading it only if it is not cached yet
+pub fn get_input(config: &Config, year: u16, day: u8) -> Result<(String, Source), String> {
+    let path = cache_path(config, year, day);
+    if path.is_file() {
+        match fs::read_to_string(&path) {
+            Ok(content) => { return Ok((content, Source::Cache)); },
+            Err(e) => { return Err(format!("{}: {}", path.display(), e)); }
+        }
+    }
+
+    let content = download(config, year, day)?;
+    write(&path, &content)?;
+    return Ok((content, Source::Download));
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        if let Err(e) = fs::create_dir_all(dir) {
+            return Err(format!("{}: {}", dir.display(), e));
+        }
+    }
+    match fs::write(path, content) {
+        Ok(()) => { Ok(()) },
+        Err(e) => { Err(format!("{}: {}", path.display(), e)) },
+    }
+}
+
+/// Get the input of a day and copy it to `destination`, returning where it
+/// was read from
+///
+/// An existing destination with a different content (e.g. an input edited
+/// by hand) is only replaced if `force` is set.
+pub fn fetch(config: &Config, year: u16, day: u8, destination: &Path, force: bool) -> Result<Source, String> {
+    let (content, source) = get_input(config, year, day)?;
+    if let Ok(existing) = fs::read_to_string(destination) {
+        if existing == content {
+            return Ok(source);
+        }
+        if !force {
+            return Err(format!("{} already exists with a different content, use --force to replace it", destination.display()));
+        }
+    }
+    write(destination, &content)?;
+    return Ok(source);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    const INPUT: &str = "1000\n2000\n\n3000\n";
+
+    /// Serve inputs like the website would, counting the requests made
+    fn stand_in() -> (String, Arc<AtomicUsize>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let authorized = request.headers().iter()
+                    .any(|header| header.field.equiv("Cookie") && header.value == "session=token");
+                let response = if !authorized {
+                    tiny_http::Response::from_string("Puzzle inputs differ by user").with_status_code(400)
+                } else if request.url() == "/2022/day/1/input" {
+                    tiny_http::Response::from_string(INPUT)
+                } else {
+                    tiny_http::Response::from_string("Not found").with_status_code(404)
+                };
+                let _ = request.respond(response);
+            }
+        });
+        return (url, requests);
+    }
+
+    fn stand_in_config(url: &str, cache_dir: &Path, session: &str) -> Config {
+        return Config {
+            session: Some(String::from(session)),
+            contact: Some(String::from("someone@example.com")),
+            base_url: Some(String::from(url)),
+            cache_dir: Some(cache_dir.to_path_buf()),
+        };
+    }
+
+    #[test]
+    fn downloads_once_then_uses_cache() {
+        let (url, requests) = stand_in();
+        let dir = tempfile::tempdir().unwrap();
+        let config = stand_in_config(&url, &dir.path().join("cache"), "token");
+        let destination = dir.path().join("day_1").join("input.txt");
+
+        assert_eq!(fetch(&config, 2022, 1, &destination, false), Ok(Source::Download));
+        assert_eq!(fetch(&config, 2022, 1, &destination, false), Ok(Source::Cache));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert_eq!(fs::read_to_string(&destination).unwrap(), INPUT);
+        assert_eq!(fs::read_to_string(cache_path(&config, 2022, 1)).unwrap(), INPUT);
+    }
+
+    #[test]
+    fn keeps_edited_inputs_unless_forced() {
+        let (url, _) = stand_in();
+        let dir = tempfile::tempdir().unwrap();
+        let config = stand_in_config(&url, &dir.path().join("cache"), "token");
+        let destination = dir.path().join("input.txt");
+        fs::write(&destination, "edited").unwrap();
+
+        assert!(fetch(&config, 2022, 1, &destination, false).unwrap_err().contains("--force"));
+        assert_eq!(fs::read_to_string(&destination).unwrap(), "edited");
+        assert_eq!(fetch(&config, 2022, 1, &destination, true), Ok(Source::Cache));
+        assert_eq!(fs::read_to_string(&destination).unwrap(), INPUT);
+    }
+
+    #[test]
+    fn requires_a_contact() {
+        let (url, requests) = stand_in();
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = stand_in_config(&url, dir.path(), "token");
+        assert_eq!(config.user_agent(), Ok(String::from("aoc solutions runner (+someone@example.com)")));
+        config.contact = None;
+        assert!(fetch(&config, 2022, 1, &dir.path().join("input.txt"), false).unwrap_err().contains("contact"));
+        assert_eq!(requests.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn reports_rejected_requests() {
+        let (url, _) = stand_in();
+        let dir = tempfile::tempdir().unwrap();
+        let destination = dir.path().join("input.txt");
+
+        let config = stand_in_config(&url, dir.path(), "expired");
+        assert!(fetch(&config, 2022, 1, &destination, false).unwrap_err().contains("status 400"));
+        let config = stand_in_config(&url, dir.path(), "token");
+        assert!(fetch(&config, 2022, 2, &destination, false).unwrap_err().contains("status 404"));
+        assert!(!destination.exists());
+        assert!(!cache_path(&config, 2022, 1).exists());
+    }
+}
@@ -0,0 +1,229 @@
+use aoc_core::cli::{self, Challenge, Day};
+use aoc_core::params::Overrides;
+use aoc_core::{expected, input, visualize, Answer, Params};
+use clap::{Parser, Subcommand};
+use std::io::{self, IsTerminal};
+use std::path::Path;
+use std::process;
+
+mod bench;
+mod check;
+mod config;
+mod fetch;
+mod progress;
+mod record;
+mod registry;
+mod scaffold;
+mod submit;
+mod summary;
+mod watch;
+
+/// Advent of Code solutions runner
+#[derive(Debug, Parser)]
+struct Arg {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run a single challenge, or every registered one with --all
+    Run(Challenge),
+    /// Run both challenges of a day on all its inputs and compare the answers
+    /// to the expected ones
+    Check(cli::Check),
+    /// Time the parsing and solving of the challenges
+    Bench(cli::Bench),
+    /// Show the parameters of a day and their default values
+    Params(Day),
+    /// Create the crate of a new day and register it with the runner
+    New(cli::New),
+    /// Download the input of a day to its directory, unless already cached
+    Fetch(cli::Fetch),
+    /// Compute the answer of a challenge on the input of its day and submit it
+    Submit(cli::Submit),
+    /// Regenerate the star calendars of the README files from the registry
+    /// and the recorded answers
+    Progress(cli::Progress),
+    /// Rebuild and check a day on every change of its sources or inputs
+    Watch(Day),
+    /// List every registered challenge
+    List,
+}
+
+fn find_entry(year: u16, day: u8) -> &'static registry::Entry {
+    match registry::find(year, day) {
+        Some(entry) => { entry },
+        None => { panic!("[ERROR] No solution registered for {} day {}", year, day); }
+    }
+}
+
+fn run_challenge(challenge: &Challenge) {
+    // Present unless --all is given, as enforced by the argument parser
+    let (year, day, challenge_num, filename) = match (challenge.year, challenge.day, challenge.challenge_num, &challenge.filename) {
+        (Some(year), Some(day), Some(challenge_num), Some(filename)) => { (year, day, challenge_num, filename) },
+        _ => { panic!("[ERROR] Missing challenge arguments"); }
+    };
+    let entry = find_entry(year, day);
+    let solution = (entry.build)();
+    let recorded = expected::overrides_for(Path::new(filename));
+    let given = challenge.params.iter().cloned().collect::<Overrides>();
+    let params = match Params::resolve(&solution.params(), challenge_num, &[&recorded, &given]) {
+        Ok(params) => { params },
+        Err(e) => {
+            eprintln!("[ERROR] {}", e);
+            process::exit(1);
+        }
+    };
+    let input = input::load(filename);
+    if challenge.visualize {
+        visualize::enable(Box::new(visualize::Terminal::new(challenge.fps, io::stdout().is_terminal())), challenge.skip);
+    }
+    if let Some(path) = &challenge.record {
+        match record::Recorder::new(path, challenge.fps, challenge.scale) {
+            Ok(recorder) => { visualize::enable(Box::new(recorder), challenge.skip); },
+            Err(e) => {
+                eprintln!("[ERROR] {}", e);
+                process::exit(1);
+            }
+        }
+    }
+    match solution.solve(challenge_num, &input, &params) {
+        Ok(ans) => { println!("Answer: {}", ans); },
+        Err(e) => {
+            eprintln!("{}", e.with_file(filename));
+            process::exit(1);
+        }
+    }
+    if let Err(e) = visualize::finish() {
+        eprintln!("[ERROR] Could not write the frames: {}", e);
+        process::exit(1);
+    }
+}
+
+fn submit_answer(args: &cli::Submit) -> Result<(), String> {
+    let entry = find_entry(args.year, args.day);
+    let dir = expected::day_dir(args.year, args.day);
+    let path = dir.join(&args.input);
+    let solution = (entry.build)();
+    let params = Params::resolve(&solution.params(), args.challenge_num, &[&expected::overrides_for(&path)])?;
+    let answer = match solution.solve(args.challenge_num, &input::load(&path.to_string_lossy()), &params) {
+        Ok(Answer::Screen(_)) => { return Err(String::from("Screen answers must be read and submitted by hand")); },
+        Ok(answer) => { answer.to_string() },
+        Err(e) => { return Err(e.with_file(&path.to_string_lossy()).to_string()); }
+    };
+    println!("Answer: {}", answer);
+
+    let config = config::Config::load();
+    let mut history = submit::History::load(&submit::history_path(&config))?;
+    let submission = submit::submit(&config, &mut history, args.year, args.day, args.challenge_num, &answer, submit::now())?;
+    for warning in submission.warnings.iter() {
+        eprintln!("[WARNING] {}", warning);
+    }
+    println!("Verdict: {}", submission.attempt.verdict);
+    if let Some(retry_after) = submission.attempt.retry_after {
+        println!("Next answer accepted in {}s", retry_after - submission.attempt.time);
+    }
+    return Ok(());
+}
+
+fn main() {
+    let arg = Arg::parse();
+
+    match arg.command {
+        Command::Run(challenge) => {
+            if challenge.all {
+                if !summary::run(challenge.year, challenge.output.as_deref()) {
+                    process::exit(1);
+                }
+            } else {
+                run_challenge(&challenge);
+            }
+        },
+        Command::Check(args) => {
+            let entry = find_entry(args.day.year, args.day.day);
+            let checks = check::check_day(entry, args.staged);
+            for check in checks.iter() {
+                println!("{}", check);
+            }
+            if checks.iter().any(|check| check.status == check::Status::Fail) {
+                process::exit(1);
+            }
+        },
+        Command::Bench(args) => {
+            if !bench::run(&args) {
+                process::exit(1);
+            }
+        },
+        Command::Params(day) => {
+            let entry = find_entry(day.year, day.day);
+            for param in (entry.build)().params().iter() {
+                println!("{}", param);
+            }
+        },
+        Command::New(args) => {
+            match scaffold::create(&expected::repository_root(), args.year, args.day, &args.params) {
+                Ok(()) => {
+                    println!("Created {}", expected::day_dir(args.year, args.day).display());
+                },
+                Err(e) => {
+                    eprintln!("[ERROR] {}", e);
+                    process::exit(1);
+                }
+            }
+        },
+        Command::Fetch(args) => {
+            let day = &args.day;
+            let config = config::Config::load();
+            let destination = expected::day_dir(day.year, day.day).join("input.txt");
+            match fetch::fetch(&config, day.year, day.day, &destination, args.force) {
+                Ok(source) => {
+                    let origin = match source {
+                        fetch::Source::Cache => { "cache" },
+                        fetch::Source::Download => { "website" },
+                    };
+                    println!("{} day {}: input copied from the {} to {}", day.year, day.day, origin, destination.display());
+                },
+                Err(e) => {
+                    eprintln!("[ERROR] {}", e);
+                    process::exit(1);
+                }
+            }
+        },
+        Command::Submit(args) => {
+            if let Err(e) = submit_answer(&args) {
+                eprintln!("[ERROR] {}", e);
+                process::exit(1);
+            }
+        },
+        Command::Progress(args) => {
+            match progress::update(&expected::repository_root(), !args.check) {
+                Ok(outdated) => {
+                    for path in outdated.iter() {
+                        let action = if args.check { "Out of date" } else { "Updated" };
+                        println!("{}: {}", action, path.display());
+                    }
+                    if args.check && !outdated.is_empty() {
+                        process::exit(1);
+                    }
+                },
+                Err(e) => {
+                    eprintln!("[ERROR] {}", e);
+                    process::exit(1);
+                }
+            }
+        },
+        Command::Watch(day) => {
+            find_entry(day.year, day.day);
+            if let Err(e) = watch::watch(&expected::repository_root(), day.year, day.day) {
+                eprintln!("[ERROR] {}", e);
+                process::exit(1);
+            }
+        },
+        Command::List => {
+            for entry in registry::SOLUTIONS.iter() {
+                println!("{} day {}", entry.year, entry.day);
+            }
+        },
+    }
+}
@@ -0,0 +1,220 @@
+use aoc_core::expected;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::registry;
+
+/// First year of the event
+const FIRST_YEAR: u16 = 2015;
+
+/// Markers delimiting the generated part of a README file
+const START_MARKER: &str = "<!-- progress:start -->";
+const END_MARKER: &str = "<!-- progress:end -->";
+
+/// State of a part of a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Star {
+    /// The answer to the puzzle input is recorded
+    Solved,
+    /// A solution exists but its answer to the puzzle input is not recorded
+    Unverified,
+    Missing,
+}
+
+impl Star {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Self::Solved => { "★" },
+            Self::Unverified => { "☆" },
+            Self::Missing => { "·" },
+        }
+    }
+}
+
+/// State of both parts of every day of a year
+pub type Calendar = [[Star; 2]; 25];
+
+fn count(calendar: &Calendar, star: Star) -> usize {
+    return calendar.iter().flatten().filter(|s| **s == star).count();
+}
+
+/// State of the days of a year, from the registry and the answers recorded for
+/// the `input.txt` of each day
+///
+/// Days solved outside of the runner (e.g. the C solutions) have a directory
+/// but can not be verified.
+pub fn calendar(year: u16) -> Calendar {
+    let mut calendar = [[Star::Missing; 2]; 25];
+    for (index, stars) in calendar.iter_mut().enumerate() {
+        let day = index as u8 + 1;
+        let dir = expected::day_dir(year, day);
+        if registry::find(year, day).is_none() {
+            if dir.is_dir() {
+                *stars = [Star::Unverified; 2];
+            }
+            continue;
+        }
+        let answers = expected::load(&dir);
+        for (part, star) in stars.iter_mut().enumerate() {
+            let recorded = answers.get("input.txt").and_then(|e| e.get_answer(part as u8 + 1));
+            *star = if recorded.is_some() { Star::Solved } else { Star::Unverified };
+        }
+    }
+    return calendar;
+}
+
+/// Years having at least a day directory, most recent first
+fn started_years(root: &Path) -> Vec<u16> {
+    let mut years = Vec::new();
+    if let Ok(entries) = fs::read_dir(root) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if let Ok(year) = name.parse::<u16>() {
+                if year >= FIRST_YEAR && entry.path().is_dir() {
+                    years.push(year);
+                }
+            }
+        }
+    }
+    for entry in registry::SOLUTIONS.iter() {
+        if !years.contains(&entry.year) {
+            years.push(entry.year);
+        }
+    }
+    years.sort_unstable_by(|a, b| b.cmp(a));
+    return years;
+}
+
+const LEGEND: &str = "★ solved, ☆ solved without recorded answer, · not solved yet";
+
+/// Star calendar of every started year, one row per year
+pub fn render_overview(calendars: &[(u16, Calendar)]) -> String {
+    let mut lines = vec![
+        format!("| Year | Stars | {} |", (1..=25).map(|day| day.to_string()).collect::<Vec<String>>().join(" | ")),
+        format!("|---|---|{}", "---|".repeat(25)),
+    ];
+    for (year, calendar) in calendars.iter() {
+        let days = calendar.iter().map(|stars| format!("{}{}", stars[0].symbol(), stars[1].symbol()));
+        lines.push(format!(
+            "| [{}](./{}/) | {}/50 | {} |",
+            year, year, count(calendar, Star::Solved), days.collect::<Vec<String>>().join(" | ")
+        ));
+    }
+    lines.push(String::new());
+    lines.push(LEGEND.to_string());
+
+    let newest = calendars.iter().map(|(year, _)| *year).max().unwrap_or(FIRST_YEAR);
+    let not_started = (FIRST_YEAR..=newest).rev()
+        .filter(|year| !calendars.iter().any(|(y, _)| y == year))
+        .map(|year| year.to_string())
+        .collect::<Vec<String>>();
+    if !not_started.is_empty() {
+        lines.push(String::new());
+        lines.push(format!("Not started: {}", not_started.join(", ")));
+    }
+    return lines.join("\n");
+}
+
+/// Star calendar of a year, one row per day
+pub fn render_year(calendar: &Calendar) -> String {
+    let mut lines = vec![
+        format!(
+            "{}/50 stars, {} more without recorded answer",
+            count(calendar, Star::Solved), count(calendar, Star::Unverified)
+        ),
+        String::new(),
+        String::from("| Day | Part 1 | Part 2 |"),
+        String::from("|---|---|---|"),
+    ];
+    for (index, stars) in calendar.iter().enumerate() {
+        let day = index + 1;
+        let name = if stars[0] == Star::Missing { day.to_string() } else { format!("[{}](./day_{}/)", day, day) };
+        lines.push(format!("| {} | {} | {} |", name, stars[0].symbol(), stars[1].symbol()));
+    }
+    lines.push(String::new());
+    lines.push(LEGEND.to_string());
+    return lines.join("\n");
+}
+
+/// Replace the generated part of a README file, appending it if the file has
+/// none yet
+pub fn replace_section(content: &str, section: &str) -> Result<String, String> {
+    let generated = format!("{}\n{}\n{}", START_MARKER, section, END_MARKER);
+    match (content.find(START_MARKER), content.find(END_MARKER)) {
+        (Some(start), Some(end)) if start < end => {
+            return Ok(format!("{}{}{}", &content[..start], generated, &content[end + END_MARKER.len()..]));
+        },
+        (None, None) => {
+            return Ok(format!("{}\n\n{}\n", content.trim_end(), generated));
+        },
+        _ => { return Err(format!("Unbalanced {} and {} markers", START_MARKER, END_MARKER)); }
+    }
+}
+
+/// Regenerate the progress of the README file of the repository and of the
+/// existing README files of the years
+///
+/// Returns the files that were out of date, relative to `root`, they are only
+/// rewritten if `write` is set.
+pub fn update(root: &Path, write: bool) -> Result<Vec<PathBuf>, String> {
+    let calendars = started_years(root).into_iter()
+        .map(|year| (year, calendar(year)))
+        .collect::<Vec<(u16, Calendar)>>();
+
+    let mut sections = vec![(root.join("README.md"), render_overview(&calendars))];
+    for (year, calendar) in calendars.iter() {
+        let path = root.join(year.to_string()).join("README.md");
+        if path.is_file() {
+            sections.push((path, render_year(calendar)));
+        }
+    }
+
+    let mut outdated = Vec::new();
+    for (path, section) in sections.iter() {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => { content },
+            Err(e) => { return Err(format!("{}: {}", path.display(), e)); }
+        };
+        let updated = match replace_section(&content, section) {
+            Ok(updated) => { updated },
+            Err(e) => { return Err(format!("{}: {}", path.display(), e)); }
+        };
+        if updated == content {
+            continue;
+        }
+        if write {
+            if let Err(e) = fs::write(path, &updated) {
+                return Err(format!("{}: {}", path.display(), e));
+            }
+        }
+        outdated.push(path.strip_prefix(root).unwrap_or(path).to_path_buf());
+    }
+    return Ok(outdated);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replaces_generated_section() {
+        let content = replace_section("# 2022\n\nIntro\n", "old").unwrap();
+        assert_eq!(content, "# 2022\n\nIntro\n\n<!-- progress:start -->\nold\n<!-- progress:end -->\n");
+        let content = replace_section(&format!("{}Outro\n", content), "new").unwrap();
+        assert_eq!(content, "# 2022\n\nIntro\n\n<!-- progress:start -->\nnew\n<!-- progress:end -->\nOutro\n");
+        assert!(replace_section("<!-- progress:end -->\n<!-- progress:start -->", "new").is_err());
+    }
+
+    #[test]
+    fn renders_calendars() {
+        let mut calendar = [[Star::Missing; 2]; 25];
+        calendar[0] = [Star::Solved; 2];
+        calendar[1] = [Star::Solved, Star::Unverified];
+        let overview = render_overview(&[(2017, calendar)]);
+        assert!(overview.contains("| [2017](./2017/) | 3/50 | ★★ | ★☆ | ·· |"));
+        assert!(overview.ends_with("Not started: 2016, 2015"));
+        let year = render_year(&calendar);
+        assert!(year.starts_with("3/50 stars, 1 more without recorded answer"));
+        assert!(year.contains("| [2](./day_2/) | ★ | ☆ |\n| 3 | · | · |"));
+    }
+}
@@ -0,0 +1,314 @@
+use aoc_core::visualize::{Cell, Color, Output, CLEAR_SCREEN, RESET};
+use aoc_core::{Frame, Point2};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Fastest speed of the recordings, as GIF viewers slow down the frames
+/// shown for less than 2 hundredths of a second
+const MAX_FPS: f64 = 50.0;
+
+/// Colors of the GIF palette after the background, uncolored cells being
+/// left as background
+const COLORS: [Color; 8] = [
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+    Color::Gray,
+];
+const BACKGROUND: [u8; 3] = [16, 16, 24];
+
+/// Kind of file written, from its extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Gif,
+    /// Asciinema v2 recording of the frames drawn in a terminal
+    Cast,
+}
+
+impl Format {
+    fn of(path: &Path) -> Result<Format, String> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("gif") => { Ok(Format::Gif) },
+            Some("cast") => { Ok(Format::Cast) },
+            _ => { Err(format!("Unknown recording format of {}, expected a .gif or .cast file", path.display())) },
+        }
+    }
+}
+
+/// Differences of a frame from the previous one
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Change {
+    /// New title, if it changed
+    title: Option<String>,
+    /// Cells plotted or changed, and cells no longer plotted
+    cells: Vec<(Point2<i64>, Option<Cell>)>,
+}
+
+/// Frames of a simulation, kept as the cells changing from one frame to the
+/// next so that long simulations fit in memory
+#[derive(Debug, Clone, Default)]
+struct Recording {
+    current: HashMap<Point2<i64>, Cell>,
+    title: Option<String>,
+    changes: Vec<Change>,
+    /// Smallest box holding every cell of every frame
+    bounds: Option<(Point2<i64>, Point2<i64>)>,
+}
+
+impl Recording {
+    fn add(&mut self, frame: &Frame) {
+        let mut change = Change::default();
+        self.current.retain(|pos, _| {
+            let plotted = frame.get(pos.x, pos.y).is_some();
+            if !plotted {
+                change.cells.push((*pos, None));
+            }
+            return plotted;
+        });
+        for (pos, cell) in frame.cells() {
+            if self.current.insert(pos, cell) != Some(cell) {
+                change.cells.push((pos, Some(cell)));
+            }
+        }
+        if frame.title() != self.title.as_deref() {
+            self.title = frame.title().map(String::from);
+            change.title = Some(String::from(frame.title().unwrap_or("")));
+        }
+
+        if let Some((min, max)) = frame.bounds() {
+            self.bounds = match self.bounds {
+                Some((all_min, all_max)) => {
+                    Some((
+                        Point2::new(all_min.x.min(min.x), all_min.y.min(min.y)),
+                        Point2::new(all_max.x.max(max.x), all_max.y.max(max.y)),
+                    ))
+                },
+                None => { Some((min, max)) },
+            };
+        }
+        self.changes.push(change);
+    }
+
+    fn len(&self) -> usize {
+        return self.changes.len();
+    }
+
+    /// Top left corner and size in cells of the area of the recording
+    fn area(&self) -> (Point2<i64>, usize, usize) {
+        match self.bounds {
+            Some((min, max)) => { return (min, (max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize); },
+            None => { return (Point2::new(0, 0), 0, 0); }
+        }
+    }
+}
+
+fn frames_per_second(fps: f64) -> f64 {
+    return if fps > 0.0 && fps < MAX_FPS { fps } else { MAX_FPS };
+}
+
+/// Write the recording as an asciicast, each frame drawing the cells that
+/// changed in the frame drawn by the first one
+fn write_cast(recording: &Recording, fps: f64, out: &mut impl Write) -> io::Result<()> {
+    let (min, width, height) = recording.area();
+    let title_width = recording.changes.iter()
+        .filter_map(|change| change.title.as_ref().map(|title| title.chars().count()))
+        .max()
+        .unwrap_or(0);
+    // Title on the first row, then the frame in its border
+    let header = serde_json::json!({ "version": 2, "width": title_width.max(width + 2), "height": height + 3 });
+    writeln!(out, "{}", header)?;
+
+    let border = "━".repeat(width);
+    let mut screen = format!("{}\x1b[2;1H┏{}┓", CLEAR_SCREEN, border);
+    for row in 0..height {
+        screen.push_str(&format!("\x1b[{};1H┃{}┃", row + 3, " ".repeat(width)));
+    }
+    screen.push_str(&format!("\x1b[{};1H┗{}┛", height + 3, border));
+
+    let fps = frames_per_second(fps);
+    for (index, change) in recording.changes.iter().enumerate() {
+        if let Some(title) = &change.title {
+            screen.push_str(&format!("\x1b[1;1H\x1b[2K{}", title));
+        }
+        for (pos, cell) in change.cells.iter() {
+            screen.push_str(&format!("\x1b[{};{}H", pos.y - min.y + 3, pos.x - min.x + 2));
+            match cell {
+                Some(Cell { symbol, color: Some(color) }) => { screen.push_str(&format!("{}{}{}", color.ansi(), symbol, RESET)); },
+                Some(cell) => { screen.push(cell.symbol); },
+                None => { screen.push(' '); },
+            }
+        }
+        if index + 1 == recording.changes.len() {
+            // Leave the cursor under the frame
+            screen.push_str(&format!("\x1b[{};1H", height + 4));
+        }
+        writeln!(out, "{}", serde_json::json!([index as f64 / fps, "o", screen]))?;
+        screen.clear();
+    }
+    return Ok(());
+}
+
+/// Write the recording as an animated GIF, each cell drawn as a square of
+/// `scale` pixels of its color
+///
+/// Each frame only holds the area of the cells that changed, drawn over the
+/// previous frames.
+fn write_gif(recording: &Recording, fps: f64, scale: u16, out: &mut impl Write) -> io::Result<()> {
+    let (min, width, height) = recording.area();
+    let size = |cells: usize| u16::try_from(cells.max(1) * scale as usize).ok();
+    let (pixel_width, pixel_height) = match (size(width), size(height)) {
+        (Some(pixel_width), Some(pixel_height)) => { (pixel_width, pixel_height) },
+        _ => { return Err(io::Error::other(format!("{}x{} cells do not fit in a GIF at scale {}", width, height, scale))); }
+    };
+
+    // Palette of 16 colors: the background, the colors, then unused ones
+    let mut palette = BACKGROUND.to_vec();
+    for color in COLORS.iter() {
+        palette.extend(color.rgb());
+    }
+    palette.resize(16 * 3, 0);
+    let index = |cell: &Option<Cell>| match cell.and_then(|cell| cell.color) {
+        Some(color) => { 1 + COLORS.iter().position(|&other| other == color).unwrap() as u8 },
+        None => { 0 },
+    };
+
+    let mut encoder = gif::Encoder::new(out, pixel_width, pixel_height, &palette).map_err(io::Error::other)?;
+    encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+    let delay = (100.0 / frames_per_second(fps)).round() as u16;
+    // Palette index of each cell of the area, as drawn so far
+    let mut canvas = vec![0u8; width.max(1) * height.max(1)];
+    for change in recording.changes.iter() {
+        let (mut left, mut top, mut right, mut bottom) = (usize::MAX, usize::MAX, 0, 0);
+        for (pos, cell) in change.cells.iter() {
+            let (x, y) = ((pos.x - min.x) as usize, (pos.y - min.y) as usize);
+            canvas[y * width + x] = index(cell);
+            (left, top, right, bottom) = (left.min(x), top.min(y), right.max(x), bottom.max(y));
+        }
+        if change.cells.is_empty() {
+            // Redraw a cell, the frame still has to last
+            (left, top, right, bottom) = (0, 0, 0, 0);
+        }
+
+        let (frame_width, frame_height) = ((right - left + 1) * scale as usize, (bottom - top + 1) * scale as usize);
+        let mut buffer = Vec::with_capacity(frame_width * frame_height);
+        for pixel_y in 0..frame_height {
+            let y = top + pixel_y / scale as usize;
+            for pixel_x in 0..frame_width {
+                buffer.push(canvas[y * width.max(1) + left + pixel_x / scale as usize]);
+            }
+        }
+        let frame = gif::Frame {
+            left: (left * scale as usize) as u16,
+            top: (top * scale as usize) as u16,
+            width: frame_width as u16,
+            height: frame_height as u16,
+            delay,
+            dispose: gif::DisposalMethod::Keep,
+            buffer: Cow::Owned(buffer),
+            ..gif::Frame::default()
+        };
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    return Ok(());
+}
+
+/// Records the frames of a simulation, then writes them to a file once it is
+/// over
+pub struct Recorder {
+    path: PathBuf,
+    format: Format,
+    fps: f64,
+    scale: u16,
+    recording: Recording,
+}
+
+impl Recorder {
+    pub fn new(path: &str, fps: f64, scale: u16) -> Result<Recorder, String> {
+        let path = PathBuf::from(path);
+        let format = Format::of(&path)?;
+        return Ok(Recorder { path, format, fps, scale, recording: Recording::default() });
+    }
+}
+
+impl Output for Recorder {
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        self.recording.add(frame);
+        return Ok(());
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(&self.path)?);
+        match self.format {
+            Format::Gif => { write_gif(&self.recording, self.fps, self.scale, &mut out)?; },
+            Format::Cast => { write_cast(&self.recording, self.fps, &mut out)?; },
+        }
+        out.flush()?;
+        println!("Recorded {} frames to {}", self.recording.len(), self.path.display());
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sand falling by a cell at each frame onto a rock, under a title
+    fn falling_sand() -> Recording {
+        let mut recording = Recording::default();
+        for step in 0..3 {
+            let mut frame = Frame::new().titled(if step < 2 { "Falling" } else { "At rest" });
+            frame.plot(0, 3, Cell::colored('#', Color::Gray));
+            frame.plot(1, 3, Cell::colored('#', Color::Gray));
+            frame.plot(1, step, Cell::colored('o', Color::Yellow));
+            recording.add(&frame);
+        }
+        return recording;
+    }
+
+    #[test]
+    fn records_changes() {
+        let recording = falling_sand();
+        assert_eq!(recording.len(), 3);
+        assert_eq!(recording.changes[0].cells.len(), 3);
+        assert_eq!(recording.changes[1].title, None);
+        assert_eq!(recording.changes[2].title.as_deref(), Some("At rest"));
+        let mut moved = recording.changes[2].cells.clone();
+        moved.sort_by_key(|(pos, _)| (pos.y, pos.x));
+        assert_eq!(moved, vec![(Point2::new(1, 1), None), (Point2::new(1, 2), Some(Cell::colored('o', Color::Yellow)))]);
+        assert_eq!(recording.area(), (Point2::new(0, 0), 2, 4));
+        assert_eq!(Format::of(Path::new("sand.gif")), Ok(Format::Gif));
+        assert!(Format::of(Path::new("sand.png")).is_err());
+    }
+
+    #[test]
+    fn writes_cast() {
+        let mut out = Vec::new();
+        write_cast(&falling_sand(), 10.0, &mut out).unwrap();
+        let lines = String::from_utf8(out).unwrap().lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<serde_json::Value>>();
+        assert_eq!(lines.len(), 4);
+        assert_eq!((&lines[0]["width"], &lines[0]["height"]), (&serde_json::json!(7), &serde_json::json!(7)));
+        assert_eq!(lines[2], serde_json::json!([0.1, "o", "\x1b[3;3H \x1b[4;3H\x1b[33mo\x1b[0m"]));
+    }
+
+    #[test]
+    fn writes_gif() {
+        let mut out = Vec::new();
+        write_gif(&falling_sand(), 10.0, 3, &mut out).unwrap();
+        let mut decoder = gif::DecodeOptions::new().read_info(out.as_slice()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (6, 12));
+        let mut frames = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            frames.push((frame.left, frame.top, frame.width, frame.height, frame.delay));
+        }
+        assert_eq!(frames, vec![(0, 0, 6, 12, 10), (3, 0, 3, 6, 10), (3, 3, 3, 6, 10)]);
+    }
+}
@@ -0,0 +1,56 @@
+use aoc_core::Solver;
+
+/// Builds the solution of a day
+pub type Builder = fn() -> Box<dyn Solver>;
+
+#[derive(Debug)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub build: Builder,
+}
+
+/// Registers the solution of a day
+macro_rules! entry {
+    ($year:literal, $day:literal, $solution:path) => {
+        Entry {
+            year: $year,
+            day: $day,
+            build: || Box::new($solution),
+        }
+    };
+}
+
+pub const SOLUTIONS: &[Entry] = &[
+    entry!(2021, 1, aoc_2021_day_1::Day1),
+    entry!(2021, 2, aoc_2021_day_2::Day2),
+    entry!(2021, 3, aoc_2021_day_3::Day3),
+    entry!(2021, 4, aoc_2021_day_4::Day4),
+    entry!(2021, 5, aoc_2021_day_5::Day5),
+    entry!(2021, 6, aoc_2021_day_6::Day6),
+    entry!(2021, 7, aoc_2021_day_7::Day7),
+    entry!(2021, 8, aoc_2021_day_8::Day8),
+    entry!(2021, 9, aoc_2021_day_9::Day9),
+    entry!(2021, 10, aoc_2021_day_10::Day10),
+    entry!(2021, 11, aoc_2021_day_11::Day11),
+    entry!(2022, 1, aoc_2022_day_1::Day1),
+    entry!(2022, 2, aoc_2022_day_2::Day2),
+    entry!(2022, 3, aoc_2022_day_3::Day3),
+    entry!(2022, 4, aoc_2022_day_4::Day4),
+    entry!(2022, 5, aoc_2022_day_5::Day5),
+    entry!(2022, 6, aoc_2022_day_6::Day6),
+    entry!(2022, 8, aoc_2022_day_8::Day8),
+    entry!(2022, 9, aoc_2022_day_9::Day9),
+    entry!(2022, 10, aoc_2022_day_10::Day10),
+    entry!(2022, 11, aoc_2022_day_11::Day11),
+    entry!(2022, 12, aoc_2022_day_12::Day12),
+    entry!(2022, 14, aoc_2022_day_14::Day14),
+    entry!(2022, 15, aoc_2022_day_15::Day15),
+    entry!(2022, 17, aoc_2022_day_17::Day17),
+    entry!(2022, 18, aoc_2022_day_18::Day18),
+    entry!(2022, 21, aoc_2022_day_21::Day21),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Entry> {
+    SOLUTIONS.iter().find(|entry| entry.year == year && entry.day == day)
+}
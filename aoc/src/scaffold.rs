@@ -0,0 +1,265 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const ANSWERS_TEMPLATE: &str = include_str!("../templates/answers.toml.tmpl");
+const README_TEMPLATE: &str = include_str!("../templates/README.md.tmpl");
+const RUN_TEMPLATE: &str = include_str!("../templates/run.sh.tmpl");
+
+/// Key ordering the days of the manifests and of the registry
+type Key = (u16, u8);
+
+/// Replace every `{{name}}` placeholder of a template by its value
+fn render(template: &str, values: &[(&str, String)]) -> String {
+    let mut content = String::from(template);
+    for (name, value) in values.iter() {
+        content = content.replace(&format!("{{{{{}}}}}", name), value);
+    }
+    return content;
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            return chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        },
+        _ => { return false; }
+    }
+}
+
+/// Parse the `YEAR/day_N` or `aoc_YEAR_day_N` naming a day
+fn parse_key(name: &str, separator: &str) -> Option<Key> {
+    let (year, day) = name.split_once(separator)?;
+    return Some((year.parse().ok()?, day.parse().ok()?));
+}
+
+/// Insert `line` before the first line whose key is greater than `key`,
+/// or after the last keyed line
+fn insert_sorted(lines: &mut Vec<String>, keys: &[(usize, Key)], key: Key, line: String) -> Result<(), String> {
+    if keys.iter().any(|(_, k)| *k == key) {
+        return Err(format!("{} day {} is already registered", key.0, key.1));
+    }
+    match keys.iter().find(|(_, k)| *k > key) {
+        Some((index, _)) => { lines.insert(*index, line); },
+        None => {
+            match keys.last() {
+                Some((index, _)) => { lines.insert(index + 1, line); },
+                None => { return Err(String::from("No registered day to insert after")); }
+            }
+        }
+    }
+    return Ok(());
+}
+
+fn to_lines(content: &str) -> Vec<String> {
+    return content.lines().map(String::from).collect();
+}
+
+fn from_lines(lines: &[String]) -> String {
+    return lines.join("\n") + "\n";
+}
+
+/// Add the day to the members of the workspace manifest
+pub fn add_member(manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let mut lines = to_lines(manifest);
+    let mut keys = Vec::new();
+    let mut in_members = false;
+    for (index, line) in lines.iter().enumerate() {
+        let line = line.trim();
+        if line.starts_with("members = [") {
+            in_members = true;
+        } else if in_members && line == "]" {
+            break;
+        } else if in_members {
+            let member = line.trim_matches(|c| c == '"' || c == ',');
+            if let Some(key) = parse_key(member, "/day_") {
+                keys.push((index, key));
+            }
+        }
+    }
+    insert_sorted(&mut lines, &keys, (year, day), format!("    \"{}/day_{}\",", year, day))?;
+    return Ok(from_lines(&lines));
+}
+
+/// Add the day to the dependencies of the runner, days of a new year start
+/// a new group
+pub fn add_dependency(manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let mut lines = to_lines(manifest);
+    let mut keys = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        if let Some((name, _)) = line.split_once(" = ") {
+            if let Some(key) = name.strip_prefix("aoc_").and_then(|name| parse_key(name, "_day_")) {
+                keys.push((index, key));
+            }
+        }
+    }
+    let mut line = format!("aoc_{}_day_{} = {{ path = \"../{}/day_{}\" }}", year, day, year, day);
+    if !keys.iter().any(|(_, key)| key.0 == year) {
+        // Blank line between the groups of years
+        if keys.iter().any(|(_, key)| key.0 > year) {
+            line = format!("{}\n", line);
+        } else {
+            line = format!("\n{}", line);
+        }
+    }
+    insert_sorted(&mut lines, &keys, (year, day), line)?;
+    return Ok(from_lines(&lines));
+}
+
+/// Parse an argument of an `entry!` of the registry
+fn entry_arg<T: FromStr>(arg: Option<&str>) -> Option<T> {
+    return arg?.trim().parse().ok();
+}
+
+/// Add the day to the registry of the runner
+pub fn add_entry(registry: &str, year: u16, day: u8) -> Result<String, String> {
+    let mut lines = to_lines(registry);
+    let mut keys = Vec::new();
+    let mut in_solutions = false;
+    for (index, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("pub const SOLUTIONS") {
+            in_solutions = true;
+        } else if in_solutions && trimmed == "];" {
+            // Lets new entries be appended after the last one
+            keys.push((index, (u16::MAX, u8::MAX)));
+            break;
+        } else if in_solutions {
+            if let Some(args) = trimmed.strip_prefix("entry!(") {
+                let mut split = args.split(',');
+                if let (Some(year), Some(day)) = (entry_arg(split.next()), entry_arg(split.next())) {
+                    keys.push((index, (year, day)));
+                }
+            }
+        }
+    }
+
+    let entry = format!("    entry!({}, {}, aoc_{}_day_{}::Day{}),", year, day, year, day, day);
+    insert_sorted(&mut lines, &keys, (year, day), entry)?;
+    return Ok(from_lines(&lines));
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    match fs::write(path, content) {
+        Ok(()) => { Ok(()) },
+        Err(e) => { Err(format!("{}: {}", path.display(), e)) },
+    }
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    match fs::read_to_string(path) {
+        Ok(content) => { Ok(content) },
+        Err(e) => { Err(format!("{}: {}", path.display(), e)) },
+    }
+}
+
+/// Create the crate of a day in the repository at `root` and register it
+/// with the runner
+///
+/// Each named parameter is declared by the solution, with a placeholder
+/// default value and help to fill in.
+pub fn create(root: &Path, year: u16, day: u8, params: &[String]) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day {} is not between 1 and 25", day));
+    }
+    if let Some(name) = params.iter().find(|name| !is_identifier(name)) {
+        return Err(format!("Parameter name '{}' is not a valid identifier", name));
+    }
+    let dir = root.join(year.to_string()).join(format!("day_{}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    // Every registration is prepared before writing anything
+    let workspace = root.join("Cargo.toml");
+    let runner = root.join("aoc").join("Cargo.toml");
+    let registry = root.join("aoc").join("src").join("registry.rs");
+    let workspace_content = add_member(&read(&workspace)?, year, day)?;
+    let runner_content = add_dependency(&read(&runner)?, year, day)?;
+    let registry_content = add_entry(&read(&registry)?, year, day)?;
+
+    let mut values = vec![
+        ("year", year.to_string()),
+        ("day", day.to_string()),
+        ("imports", String::from("Answer, Params, ParseError, Solution")),
+        ("params", String::new()),
+        ("overrides", String::new()),
+    ];
+    if !params.is_empty() {
+        let declarations = params.iter()
+            .map(|name| format!("            Param::new::<i64>(\"{}\", \"TODO: describe\", \"0\"),\n", name))
+            .collect::<String>();
+        let overrides = params.iter().map(|name| format!("{} = 0", name)).collect::<Vec<String>>().join(", ");
+        values[2].1 = String::from("Answer, Param, Params, ParseError, Solution");
+        values[3].1 = format!("\n    fn params(&self) -> Vec<Param> {{\n        return vec![\n{}        ];\n    }}\n", declarations);
+        values[4].1 = format!("\n# params = {{ {} }}", overrides);
+    }
+
+    if let Err(e) = fs::create_dir_all(dir.join("src")) {
+        return Err(format!("{}: {}", dir.display(), e));
+    }
+    write(&dir.join("Cargo.toml"), &render(CARGO_TEMPLATE, &values))?;
+    write(&dir.join("src").join("lib.rs"), &render(LIB_TEMPLATE, &values))?;
+    write(&dir.join("answers.toml"), &render(ANSWERS_TEMPLATE, &values))?;
+    write(&dir.join("README.md"), &render(README_TEMPLATE, &values))?;
+    write(&dir.join("input.txt"), "")?;
+    write(&dir.join("control_input.txt"), "")?;
+    let run = dir.join("run.sh");
+    write(&run, RUN_TEMPLATE)?;
+    #[cfg(unix)]
+    if let Err(e) = fs::set_permissions(&run, fs::Permissions::from_mode(0o755)) {
+        return Err(format!("{}: {}", run.display(), e));
+    }
+
+    write(&workspace, &workspace_content)?;
+    write(&runner, &runner_content)?;
+    write(&registry, &registry_content)?;
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::expected;
+
+    fn repository_file(path: &str) -> String {
+        return fs::read_to_string(expected::repository_root().join(path)).unwrap();
+    }
+
+    #[test]
+    fn every_day_has_a_run_script() {
+        for entry in crate::registry::SOLUTIONS.iter() {
+            let run = expected::day_dir(entry.year, entry.day).join("run.sh");
+            let metadata = match fs::symlink_metadata(&run) {
+                Ok(metadata) => { metadata },
+                Err(e) => { panic!("[ERROR] {}: {}", run.display(), e); }
+            };
+            assert!(metadata.is_file(), "{} is not a regular file", run.display());
+            assert_eq!(fs::read_to_string(&run).unwrap(), RUN_TEMPLATE, "{} differs from the template", run.display());
+            #[cfg(unix)]
+            assert!(metadata.permissions().mode() & 0o111 != 0, "{} is not executable", run.display());
+        }
+    }
+
+    #[test]
+    fn registers_days_in_order() {
+        let manifest = add_member(&repository_file("Cargo.toml"), 2022, 7).unwrap();
+        assert!(manifest.contains("    \"2022/day_6\",\n    \"2022/day_7\",\n    \"2022/day_8\",\n"));
+        assert!(add_member(&manifest, 2022, 7).is_err());
+
+        let manifest = add_dependency(&repository_file("aoc/Cargo.toml"), 2023, 1).unwrap();
+        assert!(manifest.contains("day_21\" }\n\naoc_2023_day_1 = { path = \"../2023/day_1\" }\n"));
+
+        let registry = add_entry(&repository_file("aoc/src/registry.rs"), 2022, 16).unwrap();
+        assert!(registry.contains("Day15),\n    entry!(2022, 16, aoc_2022_day_16::Day16),\n    entry!(2022, 17,"));
+        let registry = add_entry(&registry, 2023, 1).unwrap();
+        assert!(registry.contains("    entry!(2023, 1, aoc_2023_day_1::Day1),\n];"));
+        assert!(add_entry(&registry, 2022, 15).is_err());
+    }
+}
@@ -0,0 +1,357 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::Config;
+
+/// Name of the file recording every submitted answer, in the cache directory
+pub const HISTORY_FILE: &str = "submissions.json";
+
+/// Outcome of a submission, as told by the website
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without any hint
+    Wrong,
+    /// Submitted before the end of the wait imposed by a previous attempt
+    TooRecent,
+    /// The part was already solved, or is not unlocked yet
+    WrongLevel,
+    /// The response could not be understood
+    Unknown,
+}
+
+impl Verdict {
+    pub fn is_wrong(&self) -> bool {
+        return matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong);
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Correct => { write!(f, "correct") },
+            Self::TooHigh => { write!(f, "wrong, too high") },
+            Self::TooLow => { write!(f, "wrong, too low") },
+            Self::Wrong => { write!(f, "wrong") },
+            Self::TooRecent => { write!(f, "not checked, submitted too recently") },
+            Self::WrongLevel => { write!(f, "not checked, part already solved or locked") },
+            Self::Unknown => { write!(f, "unknown response") },
+        }
+    }
+}
+
+/// A single submitted answer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub challenge_num: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix time of the submission
+    pub time: u64,
+    /// Unix time before which the website refuses new answers
+    pub retry_after: Option<u64>,
+}
+
+/// Every answer ever submitted, kept in a JSON file
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    path: PathBuf,
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Read the history, a missing file gives an empty history
+    pub fn load(path: &Path) -> Result<History, String> {
+        let mut history = History { path: path.to_path_buf(), attempts: Vec::new() };
+        if !path.is_file() {
+            return Ok(history);
+        }
+        let content = match fs::read_to_string(path) {
+            Ok(content) => { content },
+            Err(e) => { return Err(format!("{}: {}", path.display(), e)); }
+        };
+        match serde_json::from_str(&content) {
+            Ok(attempts) => { history.attempts = attempts; },
+            Err(e) => { return Err(format!("{}: {}", path.display(), e)); }
+        }
+        return Ok(history);
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        if let Some(dir) = self.path.parent() {
+            if let Err(e) = fs::create_dir_all(dir) {
+                return Err(format!("{}: {}", dir.display(), e));
+            }
+        }
+        let content = match serde_json::to_string_pretty(&self.attempts) {
+            Ok(content) => { content },
+            Err(e) => { panic!("[ERROR] Could not serialize the submissions: {}", e); }
+        };
+        match fs::write(&self.path, content) {
+            Ok(()) => { Ok(()) },
+            Err(e) => { Err(format!("{}: {}", self.path.display(), e)) },
+        }
+    }
+
+    fn of_part(&self, year: u16, day: u8, challenge_num: u8) -> impl Iterator<Item = &Attempt> {
+        return self.attempts.iter()
+            .filter(move |a| a.year == year && a.day == day && a.challenge_num == challenge_num);
+    }
+
+    /// Check an answer against the previous attempts before submitting it
+    ///
+    /// Answers that can not be right are refused, answers contradicting the
+    /// "too high" or "too low" hints given so far only produce warnings.
+    pub fn vet(&self, year: u16, day: u8, challenge_num: u8, answer: &str, now: u64) -> Result<Vec<String>, String> {
+        let mut warnings = Vec::new();
+        let mut lowest_too_high: Option<i64> = None;
+        let mut highest_too_low: Option<i64> = None;
+
+        for attempt in self.of_part(year, day, challenge_num) {
+            if attempt.verdict == Verdict::Correct {
+                return Err(format!("Part already solved with answer {}", attempt.answer));
+            }
+            if attempt.verdict.is_wrong() && attempt.answer == answer {
+                return Err(format!("Answer {} was already submitted and is {}", answer, attempt.verdict));
+            }
+            if let Ok(value) = attempt.answer.parse::<i64>() {
+                match attempt.verdict {
+                    Verdict::TooHigh => { lowest_too_high = Some(lowest_too_high.map_or(value, |v| v.min(value))); },
+                    Verdict::TooLow => { highest_too_low = Some(highest_too_low.map_or(value, |v| v.max(value))); },
+                    _ => {},
+                }
+            }
+        }
+
+        if let Some(retry_after) = self.of_part(year, day, challenge_num).filter_map(|a| a.retry_after).max() {
+            if retry_after > now {
+                return Err(format!("Submitted too recently, wait {}s before trying again", retry_after - now));
+            }
+        }
+
+        if let Ok(value) = answer.parse::<i64>() {
+            if let Some(bound) = lowest_too_high {
+                if value >= bound {
+                    warnings.push(format!("{} is not lower than {}, which is too high", value, bound));
+                }
+            }
+            if let Some(bound) = highest_too_low {
+                if value <= bound {
+                    warnings.push(format!("{} is not higher than {}, which is too low", value, bound));
+                }
+            }
+        }
+
+        return Ok(warnings);
+    }
+}
+
+/// Path of the submission history
+pub fn history_path(config: &Config) -> PathBuf {
+    return config.cache_dir().join(HISTORY_FILE);
+}
+
+pub fn now() -> u64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => { elapsed.as_secs() },
+        Err(e) => { panic!("[ERROR] System clock before 1970: {}", e); }
+    }
+}
+
+/// Parse a duration written like "1m 23s" or "45s" into seconds
+fn parse_wait(text: &str) -> Option<u64> {
+    let mut seconds = 0;
+    for part in text.split_whitespace() {
+        let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let value = value.parse::<u64>().ok()?;
+        seconds += match unit {
+            "h" => { value * 3600 },
+            "m" => { value * 60 },
+            "s" => { value },
+            _ => { return None; }
+        };
+    }
+    return Some(seconds);
+}
+
+/// Read the verdict and the imposed wait (in seconds) from the page answering
+/// a submission
+pub fn parse_response(body: &str) -> (Verdict, Option<u64>) {
+    if body.contains("That's the right answer") {
+        return (Verdict::Correct, None);
+    }
+    if body.contains("You don't seem to be solving the right level") {
+        return (Verdict::WrongLevel, None);
+    }
+    if body.contains("You gave an answer too recently") {
+        let wait = body.split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_wait(wait));
+        return (Verdict::TooRecent, wait);
+    }
+    if body.contains("That's not the right answer") {
+        let verdict = if body.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        let wait = body.split_once("lease wait ")
+            .and_then(|(_, rest)| rest.split_once(" minute"))
+            .and_then(|(minutes, _)| if minutes == "one" { Some(1) } else { minutes.parse::<u64>().ok() })
+            .map(|minutes| minutes * 60);
+        return (verdict, wait);
+    }
+    return (Verdict::Unknown, None);
+}
+
+fn post(config: &Config, year: u16, day: u8, challenge_num: u8, answer: &str) -> Result<String, String> {
+    let url = format!("{}/{}/day/{}/answer", config.base_url(), year, day);
+    let response = ureq::post(&url)
+        .set("User-Agent", &config.user_agent()?)
+        .set("Cookie", &format!("session={}", config.session()?))
+        .send_form(&[("level", &challenge_num.to_string()), ("answer", answer)]);
+    match response {
+        Ok(response) => {
+            match response.into_string() {
+                Ok(body) => { Ok(body) },
+                Err(e) => { Err(format!("{}: {}", url, e)) },
+            }
+        },
+        Err(ureq::Error::Status(code, _)) => {
+            Err(format!("{}: status {} (is the session token valid?)", url, code))
+        },
+        Err(e) => { Err(format!("{}: {}", url, e)) },
+    }
+}
+
+/// Submitted attempt along with the warnings raised before submitting it
+#[derive(Debug)]
+pub struct Submission {
+    pub attempt: Attempt,
+    pub warnings: Vec<String>,
+}
+
+/// Vet an answer against the history, submit it and record the attempt
+pub fn submit(
+    config: &Config,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    challenge_num: u8,
+    answer: &str,
+    now: u64,
+) -> Result<Submission, String> {
+    let warnings = history.vet(year, day, challenge_num, answer, now)?;
+    let body = post(config, year, day, challenge_num, answer)?;
+    let (verdict, wait) = parse_response(&body);
+    let attempt = Attempt {
+        year,
+        day,
+        challenge_num,
+        answer: String::from(answer),
+        verdict,
+        time: now,
+        retry_after: wait.map(|wait| now + wait),
+    };
+    history.attempts.push(attempt.clone());
+    history.save()?;
+    return Ok(Submission { attempt, warnings });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    const RIGHT: &str = "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. Please wait one minute before trying again.</p></article>";
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low. Please wait 5 minutes before trying again.</p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.</p></article>";
+
+    /// Judge submissions of 2022 day 1 part 1 whose answer is 100, recording
+    /// the posted forms
+    fn mock_server() -> (String, Arc<Mutex<Vec<String>>>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let forms = Arc::new(Mutex::new(Vec::new()));
+        let received = Arc::clone(&forms);
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let mut form = String::new();
+                request.as_reader().read_to_string(&mut form).unwrap();
+                let answer = form.split('&')
+                    .find_map(|field| field.strip_prefix("answer="))
+                    .and_then(|answer| answer.parse::<i64>().ok());
+                let body = match answer {
+                    Some(100) => { RIGHT },
+                    Some(answer) if answer > 100 => { TOO_HIGH },
+                    _ => { TOO_LOW },
+                };
+                received.lock().unwrap().push(form);
+                let _ = request.respond(tiny_http::Response::from_string(body));
+            }
+        });
+        return (url, forms);
+    }
+
+    fn mock_config(url: &str, cache_dir: &Path) -> Config {
+        return Config {
+            session: Some(String::from("token")),
+            contact: Some(String::from("someone@example.com")),
+            base_url: Some(String::from(url)),
+            cache_dir: Some(cache_dir.to_path_buf()),
+        };
+    }
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(parse_response(RIGHT), (Verdict::Correct, None));
+        assert_eq!(parse_response(TOO_HIGH), (Verdict::TooHigh, Some(60)));
+        assert_eq!(parse_response(TOO_LOW), (Verdict::TooLow, Some(300)));
+        assert_eq!(parse_response(TOO_RECENT), (Verdict::TooRecent, Some(83)));
+        assert_eq!(parse_response("<html></html>"), (Verdict::Unknown, None));
+    }
+
+    #[test]
+    fn records_attempts_and_vets_answers() {
+        let (url, forms) = mock_server();
+        let dir = tempfile::tempdir().unwrap();
+        let config = mock_config(&url, dir.path());
+        let mut history = History::load(&history_path(&config)).unwrap();
+
+        let submission = submit(&config, &mut history, 2022, 1, 1, "150", 1000).unwrap();
+        assert_eq!(submission.attempt.verdict, Verdict::TooHigh);
+        assert_eq!(submission.attempt.retry_after, Some(1060));
+        assert_eq!(forms.lock().unwrap().as_slice(), ["level=1&answer=150"]);
+
+        // Throttled, then known wrong: refused without asking the website
+        assert!(submit(&config, &mut history, 2022, 1, 1, "120", 1030).unwrap_err().contains("wait 30s"));
+        assert!(submit(&config, &mut history, 2022, 1, 1, "150", 2000).unwrap_err().contains("already submitted"));
+        assert_eq!(forms.lock().unwrap().len(), 1);
+
+        // Contradicts the too high hint: submitted with a warning
+        let submission = submit(&config, &mut history, 2022, 1, 1, "200", 2000).unwrap();
+        assert_eq!(submission.warnings.len(), 1);
+        assert_eq!(submission.attempt.verdict, Verdict::TooHigh);
+
+        let submission = submit(&config, &mut history, 2022, 1, 1, "100", 3000).unwrap();
+        assert!(submission.warnings.is_empty());
+        assert_eq!(submission.attempt.verdict, Verdict::Correct);
+        assert!(submit(&config, &mut history, 2022, 1, 1, "100", 4000).unwrap_err().contains("already solved"));
+
+        // The history survives reloading
+        let reloaded = History::load(&history_path(&config)).unwrap();
+        assert_eq!(reloaded.attempts, history.attempts);
+        assert_eq!(reloaded.attempts.len(), 3);
+    }
+}
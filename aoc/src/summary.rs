@@ -0,0 +1,192 @@
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::time::Duration;
+
+use crate::check::{self, Check, Job, Status};
+use crate::registry;
+
+/// Outcome of a part of a day run on one of its inputs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Outcome {
+    pub year: u16,
+    pub day: u8,
+    pub input: String,
+    pub challenge_num: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    /// Why no answer was produced
+    pub error: Option<String>,
+    pub expected: Option<String>,
+    /// Time spent parsing the input and solving the part, in nanoseconds
+    pub time: u64,
+}
+
+impl Outcome {
+    fn new(job: &Job, check: Check) -> Self {
+        let (answer, error) = match check.answer {
+            Ok(answer) => { (Some(answer.to_string().trim().to_string()), None) },
+            Err(reason) => { (None, Some(reason.trim().to_string())) },
+        };
+        Self {
+            year: job.entry.year,
+            day: job.entry.day,
+            input: check.input,
+            challenge_num: check.challenge_num,
+            status: check.status,
+            answer,
+            error,
+            expected: check.expected.map(|expected| expected.trim().to_string()),
+            time: check.time.as_nanos() as u64,
+        }
+    }
+
+    /// Answer or error shown in the table, on a single line
+    fn shown(&self) -> String {
+        let shown = match (&self.answer, &self.error) {
+            (Some(answer), _) => { answer.clone() },
+            (None, Some(error)) => { error.clone() },
+            (None, None) => { String::new() },
+        };
+        // Screens are drawn on several lines
+        let mut lines = shown.lines();
+        let first = lines.next().unwrap_or_default().to_string();
+        match lines.count() {
+            0 => { first },
+            more => { format!("{} (+{} lines)", first, more) },
+        }
+    }
+}
+
+/// Outcomes of every part run, and how many passed, failed or had no
+/// recorded answer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub unknown: usize,
+    pub outcomes: Vec<Outcome>,
+}
+
+impl Summary {
+    fn new(outcomes: Vec<Outcome>) -> Self {
+        let count = |status: Status| outcomes.iter().filter(|outcome| outcome.status == status).count();
+        Self {
+            passed: count(Status::Pass),
+            failed: count(Status::Fail),
+            unknown: count(Status::Unknown),
+            outcomes,
+        }
+    }
+
+    /// Lines of the table of the outcomes, with a header and the totals
+    fn table(&self) -> Vec<String> {
+        let mut lines = vec![format!("{:<12} {:<20} {:>4} {:<7} {:>10}  answer", "challenge", "input", "part", "status", "time")];
+        for outcome in self.outcomes.iter() {
+            let mut answer = outcome.shown();
+            if outcome.status == Status::Fail {
+                if let Some(expected) = &outcome.expected {
+                    answer.push_str(&format!(" (expected {})", expected.lines().next().unwrap_or_default()));
+                }
+            }
+            lines.push(format!(
+                "{:<12} {:<20} {:>4} {:<7} {:>10}  {}",
+                format!("{} day {}", outcome.year, outcome.day),
+                outcome.input,
+                outcome.challenge_num,
+                outcome.status,
+                format!("{:.1?}", Duration::from_nanos(outcome.time)),
+                answer,
+            ));
+        }
+        lines.push(format!("{} passed, {} failed, {} unknown", self.passed, self.failed, self.unknown));
+        return lines;
+    }
+
+    fn to_json(&self) -> String {
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => { json },
+            Err(e) => { panic!("[ERROR] Cannot serialize the summary: {}", e); }
+        }
+    }
+}
+
+/// Run both parts of every registered day (of `year` if given) on all their
+/// inputs, in parallel, and print a table of the outcomes
+///
+/// Returns false if a part failed.
+pub fn run(year: Option<u16>, output: Option<&str>) -> bool {
+    let jobs = registry::SOLUTIONS.iter()
+        .filter(|entry| year.is_none_or(|year| year == entry.year))
+        .flat_map(check::day_jobs)
+        .collect::<Vec<Job>>();
+    let outcomes = jobs.par_iter()
+        .map(|job| Outcome::new(job, job.run()))
+        .collect::<Vec<Outcome>>();
+
+    let summary = Summary::new(outcomes);
+    for line in summary.table() {
+        println!("{}", line);
+    }
+
+    if let Some(output) = output {
+        if let Err(e) = fs::write(output, summary.to_json()) {
+            panic!("[ERROR] {}: {}", output, e);
+        }
+    }
+
+    return summary.failed == 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(challenge_num: u8, status: Status, answer: &str, expected: Option<&str>) -> Outcome {
+        return Outcome {
+            year: 2022,
+            day: 10,
+            input: String::from("input.txt"),
+            challenge_num,
+            status,
+            answer: Some(String::from(answer)),
+            error: None,
+            expected: expected.map(String::from),
+            time: 1_500_000,
+        };
+    }
+
+    fn summary() -> Summary {
+        let screen = outcome(1, Status::Unknown, "##..\n#..#", None);
+        let mut parse_error = outcome(2, Status::Fail, "", Some("EZFCHJAB"));
+        parse_error.answer = None;
+        parse_error.error = Some(String::from("[PARSE ERROR] noop 3 is not a valid instruction"));
+        return Summary::new(vec![
+            outcome(1, Status::Pass, "13180", Some("13180")),
+            outcome(2, Status::Fail, "EZFCHJAC", Some("EZFCHJAB")),
+            screen,
+            parse_error,
+        ]);
+    }
+
+    #[test]
+    fn draws_table() {
+        let table = summary().table();
+        assert_eq!(table.len(), 6);
+        assert_eq!(table[1], "2022 day 10  input.txt               1 PASS         1.5ms  13180");
+        assert_eq!(table[2], "2022 day 10  input.txt               2 FAIL         1.5ms  EZFCHJAC (expected EZFCHJAB)");
+        assert!(table[3].ends_with("UNKNOWN      1.5ms  ##.. (+1 lines)"));
+        assert!(table[4].ends_with("[PARSE ERROR] noop 3 is not a valid instruction (expected EZFCHJAB)"));
+        assert_eq!(table[5], "1 passed, 2 failed, 1 unknown");
+    }
+
+    #[test]
+    fn writes_json() {
+        let json: serde_json::Value = serde_json::from_str(&summary().to_json()).unwrap();
+        assert_eq!((json["passed"].as_u64(), json["failed"].as_u64(), json["unknown"].as_u64()), (Some(1), Some(2), Some(1)));
+        let failed = &json["outcomes"][1];
+        assert_eq!(failed["status"], "FAIL");
+        assert_eq!((failed["answer"].as_str(), failed["expected"].as_str()), (Some("EZFCHJAC"), Some("EZFCHJAB")));
+        assert_eq!(json["outcomes"][3]["answer"], serde_json::Value::Null);
+    }
+}
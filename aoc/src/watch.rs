@@ -0,0 +1,75 @@
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::env;
+use std::ffi::OsString;
+use std::path::Path;
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Time to wait for the other events of a change (e.g. an editor saving a file
+/// in several steps) before running the day
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+/// Extensions of the sources, inputs and answers files
+const WATCHED_EXTENSIONS: &[&str] = &["rs", "txt", "toml"];
+
+/// Whether the event changes a file the answers depend on
+fn is_relevant(event: &Event) -> bool {
+    if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)) {
+        return false;
+    }
+    return event.paths.iter().any(|path| {
+        path.extension().is_some_and(|extension| WATCHED_EXTENSIONS.iter().any(|watched| extension == *watched))
+    });
+}
+
+/// Rebuild the runner and check the day, running the puzzle input only once
+/// the control inputs pass
+fn run_once(root: &Path, year: u16, day: u8) {
+    // Clear the terminal so only the outcome of the last change is shown
+    print!("\x1b[2J\x1b[H");
+    println!("{} day {}: rebuilding", year, day);
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let status = Command::new(cargo)
+        .current_dir(root)
+        .args(["run", "--release", "--quiet", "-p", "aoc", "--", "check", "--staged"])
+        .args([year.to_string(), day.to_string()])
+        .status();
+    match status {
+        Ok(status) if status.success() => { println!("\nAll answers match, watching for changes"); },
+        Ok(_) => { println!("\nWatching for changes"); },
+        Err(e) => { eprintln!("[ERROR] Cannot run cargo: {}", e); }
+    }
+}
+
+/// Check the day, then again on every change of its sources, inputs or
+/// answers, or of the shared code
+pub fn watch(root: &Path, year: u16, day: u8) -> Result<(), String> {
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(sender) {
+        Ok(watcher) => { watcher },
+        Err(e) => { return Err(format!("Cannot watch files: {}", e)); }
+    };
+    let dir = root.join(year.to_string()).join(format!("day_{}", day));
+    for path in [dir, root.join("aoc_core").join("src")] {
+        if let Err(e) = watcher.watch(&path, RecursiveMode::Recursive) {
+            return Err(format!("{}: {}", path.display(), e));
+        }
+    }
+
+    run_once(root, year, day);
+    loop {
+        let event = match receiver.recv() {
+            Ok(event) => { event },
+            Err(_) => { return Err(String::from("File watcher stopped")); }
+        };
+        let mut changed = matches!(event, Ok(event) if is_relevant(&event));
+        // Events of the same change are handled by a single run
+        while let Ok(event) = receiver.recv_timeout(SETTLE_TIME) {
+            changed |= matches!(event, Ok(event) if is_relevant(&event));
+        }
+        if changed {
+            run_once(root, year, day);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use clap::Args;
+
+use crate::params;
+
+/// Arguments identifying a challenge and the input to run it on, or the
+/// year of the days to run with `--all`
+#[derive(Debug, Clone, Args)]
+pub struct Challenge {
+    /// Run both challenges of every registered day (of YEAR if given) on all
+    /// their inputs
+    #[arg(long)]
+    pub all: bool,
+
+    /// Year of the challenge
+    #[arg(required_unless_present = "all")]
+    pub year: Option<u16>,
+
+    /// Day of the challenge
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    pub day: Option<u8>,
+
+    /// Challenge to run (1 or 2)
+    #[arg(required_unless_present = "all", conflicts_with = "all", value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub challenge_num: Option<u8>,
+
+    /// Input file, - to read the standard input
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    pub filename: Option<String>,
+
+    /// Value of a parameter of the day (e.g. --param row=10 for 2022 day 15),
+    /// replacing the one recorded for the input or the default one
+    #[arg(short, long = "param", value_name = "NAME=VALUE", value_parser = params::parse_assignment, conflicts_with = "all")]
+    pub params: Vec<(String, String)>,
+
+    /// Write the summary of --all to this JSON file
+    #[arg(short, long, requires = "all")]
+    pub output: Option<String>,
+
+    /// Show the frames of the simulation of the day while it runs
+    #[arg(long, group = "frames", conflicts_with = "all")]
+    pub visualize: bool,
+
+    /// Record the frames of the simulation of the day to an animated GIF
+    /// (.gif) or asciicast (.cast) file
+    #[arg(long, value_name = "FILE", group = "frames", conflicts_with_all = ["all", "visualize"])]
+    pub record: Option<String>,
+
+    /// Frames per second: the most shown with --visualize (no limit if 0),
+    /// the speed of the recording with --record
+    #[arg(long, default_value_t = 10.0, requires = "frames")]
+    pub fps: f64,
+
+    /// Number of frames left out after each shown or recorded one
+    #[arg(long, default_value_t = 0, requires = "frames")]
+    pub skip: usize,
+
+    /// Size in pixels of the square drawing a cell in GIF recordings
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..), requires = "record")]
+    pub scale: u16,
+}
+
+/// Arguments identifying a day
+#[derive(Debug, Clone, Args)]
+pub struct Day {
+    /// Year of the challenge
+    pub year: u16,
+
+    /// Day of the challenge
+    pub day: u8,
+}
+
+/// Arguments of the check of the answers of a day
+#[derive(Debug, Clone, Args)]
+pub struct Check {
+    #[command(flatten)]
+    pub day: Day,
+
+    /// Only run the puzzle input once every control input passes
+    #[arg(long)]
+    pub staged: bool,
+}
+
+/// Arguments of the download of the input of a day
+#[derive(Debug, Clone, Args)]
+pub struct Fetch {
+    #[command(flatten)]
+    pub day: Day,
+
+    /// Replace the input of the day even if its content differs
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Arguments of the benchmark of the solutions
+#[derive(Debug, Clone, Args)]
+pub struct Bench {
+    /// Only benchmark the challenges of this year
+    pub year: Option<u16>,
+
+    /// Only benchmark the challenges of this day
+    pub day: Option<u8>,
+
+    /// Number of measured runs of each challenge
+    #[arg(short, long, default_value_t = 10)]
+    pub runs: usize,
+
+    /// Number of runs of each challenge before measuring
+    #[arg(short, long, default_value_t = 1)]
+    pub warmup: usize,
+
+    /// Input file of each day to run the challenges on
+    #[arg(short, long, default_value = "input.txt")]
+    pub input: String,
+
+    /// Write the report to this JSON file
+    #[arg(short, long)]
+    pub output: Option<String>,
+
+    /// Compare to a report written by a previous run
+    #[arg(short, long)]
+    pub baseline: Option<String>,
+
+    /// Slowdown (in percent of the baseline median) flagged as a regression
+    #[arg(short, long, default_value_t = 10.0)]
+    pub threshold: f64,
+}
+
+/// Arguments of the submission of an answer to the website
+#[derive(Debug, Clone, Args)]
+pub struct Submit {
+    /// Year of the challenge
+    pub year: u16,
+
+    /// Day of the challenge
+    pub day: u8,
+
+    /// Challenge to submit (1 or 2)
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub challenge_num: u8,
+
+    /// Input file of the day to compute the answer on
+    #[arg(short, long, default_value = "input.txt")]
+    pub input: String,
+}
+
+/// Arguments of the creation of a new day
+#[derive(Debug, Clone, Args)]
+pub struct New {
+    /// Year of the challenge
+    pub year: u16,
+
+    /// Day of the challenge
+    pub day: u8,
+
+    /// Name of a parameter of the challenges (e.g. the row number of 2022 day
+    /// 15), may be repeated
+    #[arg(short, long = "param")]
+    pub params: Vec<String>,
+}
+
+/// Arguments of the update of the progress shown in the README files
+#[derive(Debug, Clone, Args)]
+pub struct Progress {
+    /// Only check that the README files are up to date, without writing them
+    #[arg(long)]
+    pub check: bool,
+}
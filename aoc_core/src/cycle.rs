@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Repetition found in the states of a simulation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Step at which the repeated state is first met
+    pub offset: usize,
+    /// Number of steps between two occurrences of the repeated state
+    pub period: usize,
+}
+
+/// Records the states of a simulation step by step, through a fingerprint
+/// and a measured quantity (e.g. a height, a count), until one repeats
+///
+/// Once the states repeat, the quantity is assumed to grow by the same amount
+/// on every period, so it can be extrapolated to any step.
+#[derive(Debug, Clone)]
+pub struct CycleDetector<K> {
+    seen: HashMap<K, usize>,
+    values: Vec<i64>,
+    cycle: Option<Cycle>,
+}
+
+impl<K: Eq + Hash> CycleDetector<K> {
+    pub fn new() -> Self {
+        Self { seen: HashMap::new(), values: Vec::new(), cycle: None }
+    }
+
+    /// Number of steps recorded, the initial state being step 0
+    pub fn len(&self) -> usize {
+        return self.values.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.values.is_empty();
+    }
+
+    pub fn cycle(&self) -> Option<Cycle> {
+        return self.cycle;
+    }
+
+    /// Record the next step, returning the cycle once its fingerprint was
+    /// already met
+    ///
+    /// The steps recorded after the first repeat are ignored.
+    pub fn record(&mut self, fingerprint: K, value: i64) -> Option<Cycle> {
+        if self.cycle.is_none() {
+            let step = self.values.len();
+            self.values.push(value);
+            if let Some(&offset) = self.seen.get(&fingerprint) {
+                self.cycle = Some(Cycle { offset, period: step - offset });
+            } else {
+                self.seen.insert(fingerprint, step);
+            }
+        }
+        return self.cycle;
+    }
+
+    /// Quantity at `step`: the recorded one, or the one extrapolated from the
+    /// cycle
+    pub fn value_at(&self, step: usize) -> Option<i64> {
+        if step < self.values.len() {
+            return Some(self.values[step]);
+        }
+        let cycle = self.cycle?;
+        let (repeats, remainder) = ((step - cycle.offset) / cycle.period, (step - cycle.offset) % cycle.period);
+        let growth = self.values[cycle.offset + cycle.period] - self.values[cycle.offset];
+        return Some(self.values[cycle.offset + remainder] + repeats as i64 * growth);
+    }
+}
+
+impl<K: Eq + Hash> Default for CycleDetector<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Quantity measured on `state` after `steps` calls to `advance`, skipping
+/// ahead once the fingerprints of the states repeat
+///
+/// `state` is left as it was when the cycle was found, not after `steps`.
+pub fn extrapolate<S, K, A, F, M>(state: &mut S, steps: usize, mut advance: A, mut fingerprint: F, mut measure: M) -> i64
+where
+    K: Eq + Hash,
+    A: FnMut(&mut S),
+    F: FnMut(&S) -> K,
+    M: FnMut(&S) -> i64,
+{
+    let mut detector = CycleDetector::new();
+    detector.record(fingerprint(state), measure(state));
+    for _ in 0..steps {
+        advance(state);
+        if detector.record(fingerprint(state), measure(state)).is_some() {
+            break;
+        }
+    }
+    match detector.value_at(steps) {
+        Some(value) => { return value; },
+        None => { panic!("[ERROR] No value for step {}", steps); }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts 0 to 5 then loops back to 3, summing the values met
+    fn advance(state: &mut (u32, i64)) {
+        state.0 = if state.0 == 5 { 3 } else { state.0 + 1 };
+        state.1 += state.0 as i64;
+    }
+
+    #[test]
+    fn finds_cycle() {
+        let mut detector = CycleDetector::new();
+        let mut state = (0, 0);
+        let mut cycle = detector.record(state.0, state.1);
+        while cycle.is_none() {
+            advance(&mut state);
+            cycle = detector.record(state.0, state.1);
+        }
+        assert_eq!(cycle, Some(Cycle { offset: 3, period: 3 }));
+        assert_eq!((detector.len(), detector.value_at(5), detector.value_at(10)), (7, Some(15), Some(34)));
+    }
+
+    #[test]
+    fn extrapolates_quantity() {
+        let mut expected = (0, 0);
+        for _ in 0..1000 {
+            advance(&mut expected);
+        }
+        let value = extrapolate(&mut (0, 0), 1000, advance, |state| state.0, |state| state.1);
+        assert_eq!(value, expected.1);
+        assert_eq!(extrapolate(&mut (0, 0), 2, advance, |state| state.0, |state| state.1), 3);
+    }
+}
@@ -0,0 +1,112 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::params::Overrides;
+
+/// Name of the file storing the expected answers of a day, next to its inputs
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Expected answers of a single input file
+///
+/// A part without a recorded answer can not be verified. Days taking
+/// parameters record the values to use for this input if they differ from
+/// the defaults.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    #[serde(default)]
+    pub params: toml::Table,
+}
+
+impl Expected {
+    pub fn get_answer(&self, challenge_num: u8) -> Option<&str> {
+        match challenge_num {
+            1 => { self.part1.as_deref() },
+            2 => { self.part2.as_deref() },
+            n => { panic!("[ERROR] Incorrect challenge number {}", n); }
+        }
+    }
+
+    /// Values of the parameters recorded for this input
+    pub fn overrides(&self) -> Overrides {
+        let mut overrides = Overrides::new();
+        for (name, value) in self.params.iter() {
+            let value = match value {
+                toml::Value::String(s) => { s.clone() },
+                value => { value.to_string() },
+            };
+            overrides.insert(name.clone(), value);
+        }
+        return overrides;
+    }
+}
+
+/// Expected answers of a day, indexed by input file name (e.g. `control_input.txt`)
+pub type Answers = HashMap<String, Expected>;
+
+/// Root of the repository, containing one directory per year
+pub fn repository_root() -> PathBuf {
+    return Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+}
+
+/// Directory holding the inputs of a day
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    return repository_root().join(year.to_string()).join(format!("day_{}", day));
+}
+
+/// Input files of a day: every control input followed by the puzzle input
+pub fn day_inputs(dir: &Path) -> Vec<PathBuf> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => { entries },
+        Err(e) => { panic!("[ERROR] {}: {}", dir.display(), e); }
+    };
+    let mut controls = Vec::new();
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with("control_input") && name.ends_with(".txt") {
+            controls.push(name);
+        }
+    }
+    controls.sort();
+
+    let mut inputs: Vec<PathBuf> = controls.iter().map(|name| dir.join(name)).collect();
+    if dir.join("input.txt").is_file() {
+        inputs.push(dir.join("input.txt"));
+    }
+    return inputs;
+}
+
+/// Load the expected answers of a day, a day without answers file has no
+/// expected answer
+pub fn load(dir: &Path) -> Answers {
+    let path = dir.join(ANSWERS_FILE);
+    if !path.is_file() {
+        return Answers::new();
+    }
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => { content },
+        Err(e) => { panic!("[ERROR] {}: {}", path.display(), e); }
+    };
+    match toml::from_str(&content) {
+        Ok(answers) => { answers },
+        Err(e) => { panic!("[ERROR] {}: {}", path.display(), e); }
+    }
+}
+
+/// Values of the parameters recorded for an input file, in the answers file
+/// of its directory
+pub fn overrides_for(path: &Path) -> Overrides {
+    let (dir, name) = match (path.parent(), path.file_name()) {
+        (Some(dir), Some(name)) => { (dir, name.to_string_lossy()) },
+        _ => { return Overrides::new(); }
+    };
+    let dir = if dir.as_os_str().is_empty() { Path::new(".") } else { dir };
+    match load(dir).get(name.as_ref()) {
+        Some(recorded) => { recorded.overrides() },
+        None => { Overrides::new() },
+    }
+}
@@ -0,0 +1,296 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::parse::ParseError;
+use crate::point::{Direction, Direction8, Point2};
+
+/// Position of a cell: column then row, starting at the top left corner
+pub type Pos = Point2<usize>;
+
+/// Rectangular map of cells (e.g. heights, tiles), stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` by `height` cells, all set to `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Grid of the cells of a character map, one row per line
+    ///
+    /// `cell` converts a character, the error it returns points to that
+    /// character. Every line must have the same length.
+    pub fn parse<F>(input: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Result<T, String>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in input.lines() {
+            let mut count = 0;
+            for (index, c) in line.char_indices() {
+                match cell(c) {
+                    Ok(value) => { cells.push(value); },
+                    Err(message) => { return Err(ParseError::new(&line[index..index + c.len_utf8()], message)); }
+                }
+                count += 1;
+            }
+            match width {
+                None => { width = Some(count); },
+                Some(width) if width != count => {
+                    return Err(ParseError::new(line, format!("Expected {} cells per row, got {}", width, count)));
+                },
+                Some(_) => {},
+            }
+            height += 1;
+        }
+        return Ok(Self { width: width.unwrap_or(0), height, cells });
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    /// Number of cells
+    pub fn len(&self) -> usize {
+        return self.cells.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.cells.is_empty();
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        return pos.x < self.width && pos.y < self.height;
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        return self.cells.get(pos.y * self.width + pos.x);
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        return self.cells.get_mut(pos.y * self.width + pos.x);
+    }
+
+    /// Position reached from `pos` by moving of `offset`, if inside the grid
+    pub fn offset(&self, pos: Pos, offset: Point2<isize>) -> Option<Pos> {
+        return pos.checked_add_signed(offset).filter(|&pos| self.contains(pos));
+    }
+
+    /// Positions of every cell, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        return (0..self.len()).map(move |index| Point2::new(index % width, index / width));
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        return self.positions().zip(self.cells.iter_mut());
+    }
+
+    /// Cells at the given offsets of `pos`, leaving out the ones outside the
+    /// grid
+    ///
+    /// The positions do not borrow the grid, so it can be updated while
+    /// visiting them.
+    fn around<const N: usize>(&self, pos: Pos, offsets: [Point2<isize>; N]) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width, self.height);
+        return offsets.into_iter()
+            .filter_map(move |offset| pos.checked_add_signed(offset))
+            .filter(move |neighbor| neighbor.x < width && neighbor.y < height);
+    }
+
+    /// Orthogonal neighbors of `pos` inside the grid, clockwise from the one
+    /// above
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        return self.around(pos, Direction::ALL.map(Direction::offset));
+    }
+
+    /// Orthogonal and diagonal neighbors of `pos` inside the grid, clockwise
+    /// from the top left one
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        return self.around(pos, Direction8::ALL.map(Direction8::offset));
+    }
+
+    /// Positions met going from `pos` (left out) by steps of `step` until the
+    /// edge of the grid
+    pub fn ray(&self, pos: Pos, step: Point2<isize>) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width, self.height);
+        let mut current = pos;
+        return std::iter::from_fn(move || {
+            current = current.checked_add_signed(step)?;
+            return if current.x < width && current.y < height { Some(current) } else { None };
+        });
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        return &self.cells[y * self.width..(y + 1) * self.width];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return self.cells.chunks(self.width.max(1));
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        return self.cells.iter().skip(x).step_by(self.width.max(1));
+    }
+
+    /// Grid of the converted cells
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        return Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() };
+    }
+
+    /// Grid of `width` by `height` cells computed from their position
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(Pos) -> T,
+    {
+        let cells = (0..width * height).map(|index| f(Point2::new(index % width, index / width))).collect();
+        return Self { width, height, cells };
+    }
+
+    /// Grid mirrored along its main diagonal, rows becoming columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        return Self::from_fn(self.height, self.width, |pos| self[Point2::new(pos.y, pos.x)].clone());
+    }
+
+    /// Grid turned a quarter clockwise
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        return Self::from_fn(self.height, self.width, |pos| self[Point2::new(pos.y, self.height - 1 - pos.x)].clone());
+    }
+
+    /// Grid turned a quarter counterclockwise
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        return Self::from_fn(self.height, self.width, |pos| self[Point2::new(self.width - 1 - pos.y, pos.x)].clone());
+    }
+
+    /// Rows of the grid drawn with a character per cell
+    pub fn render<F>(&self, mut draw: F) -> Vec<String>
+    where
+        F: FnMut(&T) -> char,
+    {
+        return self.rows().map(|row| row.iter().map(&mut draw).collect()).collect();
+    }
+
+    /// Grid drawn with a character per cell, in a frame
+    pub fn framed<F>(&self, draw: F) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        let border = "━".repeat(self.width);
+        let mut lines = vec![format!("┏{}┓", border)];
+        lines.extend(self.render(draw).iter().map(|row| format!("┃{}┃", row)));
+        lines.push(format!("┗{}┛", border));
+        return lines.join("\n");
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => { cell },
+            None => { panic!("[ERROR] Position {} outside of the {}x{} grid", pos, self.width, self.height); }
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => { cell },
+            None => { panic!("[ERROR] Position {} outside of the {}x{} grid", pos, width, height); }
+        }
+    }
+}
+
+/// Rows of the cells, without separator
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y != 0 {
+                writeln!(f)?;
+            }
+            for cell in row.iter() {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Convert a decimal digit, to parse grids of digits
+pub fn digit(c: char) -> Result<u32, String> {
+    match c.to_digit(10) {
+        Some(digit) => { Ok(digit) },
+        None => { Err(String::from("Expected a digit")) },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_walks() {
+        let grid = Grid::parse("123\n456", digit).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point2::new(2, 1)], 6);
+        assert_eq!(grid.neighbors4(Point2::new(0, 0)).collect::<Vec<Pos>>(), vec![Point2::new(1, 0), Point2::new(0, 1)]);
+        assert_eq!(grid.neighbors8(Point2::new(1, 1)).count(), 5);
+        let ray = grid.ray(Point2::new(0, 1), Direction::Right.offset());
+        assert_eq!(ray.map(|pos| grid[pos]).collect::<Vec<u32>>(), vec![5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<u32>>(), vec![2, 5]);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+
+        let error = Grid::parse("12\n3x", digit).unwrap_err();
+        assert_eq!(error.message, "Expected a digit");
+        assert!(Grid::parse("12\n3", digit).is_err());
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = Grid::parse("12\n34\n56", digit).unwrap();
+        assert_eq!(grid.transpose().to_string(), "135\n246");
+        assert_eq!(grid.rotate_clockwise().to_string(), "531\n642");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "246\n135");
+        assert_eq!(grid.framed(|&cell| if cell % 2 == 0 { '#' } else { '.' }), "┏━━┓\n┃.#┃\n┃.#┃\n┃.#┃\n┗━━┛");
+    }
+}
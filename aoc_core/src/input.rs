@@ -0,0 +1,87 @@
+use std::borrow::Cow;
+use std::fs;
+use std::io;
+
+/// Filename standing for the standard input
+pub const STDIN: &str = "-";
+
+/// Read the whole input file, or the standard input if the filename is `-`
+pub fn load(filename: &str) -> String {
+    let data = if filename == STDIN {
+        io::read_to_string(io::stdin())
+    } else {
+        fs::read_to_string(filename)
+    };
+    match data {
+        Ok(data) => { data },
+        Err(e) => { panic!("[ERROR] {}: {}", filename, e); }
+    }
+}
+
+/// Give the input the same shape whatever editor or platform produced it
+///
+/// The byte order mark and the trailing whitespace (including the final line
+/// break) are removed, and line endings are converted to `\n`. Leading
+/// whitespace is kept as some inputs are column aligned.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input).trim_end();
+    if input.contains('\r') {
+        return Cow::Owned(input.replace("\r\n", "\n").replace('\r', "\n"));
+    }
+    return Cow::Borrowed(input);
+}
+
+/// Split the input in blocks of lines separated by blank lines
+///
+/// Each block is returned without its trailing line break, leading and
+/// consecutive blank lines do not produce empty blocks.
+pub fn paragraphs(input: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.is_empty() {
+            if let Some(block_start) = start.take() {
+                blocks.push(&input[block_start..end]);
+            }
+        } else {
+            if start.is_none() {
+                start = Some(offset);
+            }
+            end = offset + content.len();
+        }
+        offset += line.len();
+    }
+    if let Some(block_start) = start {
+        blocks.push(&input[block_start..end]);
+    }
+
+    return blocks;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_input() {
+        let input = normalize("\u{feff}1\n2");
+        assert!(matches!(input, Cow::Borrowed("1\n2")));
+        assert_eq!(normalize("1\r\n2\r\n"), "1\n2");
+        assert_eq!(normalize("1\r2\r\n3"), "1\n2\n3");
+        assert_eq!(normalize("  1\n2 \n\n \t"), "  1\n2");
+        assert_eq!(normalize("\u{feff}\r\n"), "");
+    }
+
+    #[test]
+    fn splits_paragraphs() {
+        assert_eq!(paragraphs("1\n2\n\n3\n"), vec!["1\n2", "3"]);
+        assert_eq!(paragraphs("1\r\n2\r\n\r\n3\r\n"), vec!["1\r\n2", "3"]);
+        assert_eq!(paragraphs("\n\n1\n\n\n\n2"), vec!["1", "2"]);
+        assert!(paragraphs("").is_empty());
+    }
+}
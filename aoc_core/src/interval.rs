@@ -0,0 +1,243 @@
+use std::cmp::{max, min};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+
+/// Range of integers, both ends included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        if start > end {
+            panic!("[ERROR] Interval {}-{} ends before its start", start, end);
+        }
+        Self { start, end }
+    }
+
+    /// Number of integers in the interval
+    pub fn len(&self) -> i64 {
+        return self.end - self.start + 1;
+    }
+
+    /// Always false, an interval holds at least its start
+    pub fn is_empty(&self) -> bool {
+        return false;
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        return self.start <= value && value <= self.end;
+    }
+
+    /// Whether every integer of `other` is in this interval
+    pub fn covers(&self, other: Interval) -> bool {
+        return self.start <= other.start && other.end <= self.end;
+    }
+
+    pub fn overlaps(&self, other: Interval) -> bool {
+        return self.start <= other.end && other.start <= self.end;
+    }
+
+    pub fn intersection(&self, other: Interval) -> Option<Interval> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        return Some(Interval::new(max(self.start, other.start), min(self.end, other.end)));
+    }
+}
+
+/// `start-end`, as the sections of 2022 day 4
+impl FromStr for Interval {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = parse::split_once(s, "-")?;
+        let (start, end) = (parse::number::<i64>(start)?, parse::number::<i64>(end)?);
+        if start > end {
+            return Err(ParseError::new(s, "The interval ends before its start"));
+        }
+        Ok(Self { start, end })
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Set of integers stored as the sorted, disjoint and non adjacent intervals
+/// covering them, so that wide ranges cost no more than a single value
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self { intervals: Vec::new() }
+    }
+
+    /// Intervals covering the set, in increasing order
+    pub fn intervals(&self) -> &[Interval] {
+        return &self.intervals;
+    }
+
+    /// Number of integers in the set
+    pub fn len(&self) -> i64 {
+        return self.intervals.iter().map(Interval::len).sum();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.intervals.is_empty();
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.intervals.partition_point(|interval| interval.end < value);
+        return self.intervals.get(index).is_some_and(|interval| interval.contains(value));
+    }
+
+    /// Add the integers of an interval, merging it with the ones it overlaps
+    /// or touches
+    pub fn insert(&mut self, interval: Interval) {
+        let mut merged = interval;
+        let first = self.intervals.partition_point(|other| other.end < merged.start.saturating_sub(1));
+        let mut last = first;
+        while last < self.intervals.len() && self.intervals[last].start <= merged.end.saturating_add(1) {
+            merged.start = min(merged.start, self.intervals[last].start);
+            merged.end = max(merged.end, self.intervals[last].end);
+            last += 1;
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Remove the integers of an interval, splitting the ones it cuts
+    pub fn remove(&mut self, interval: Interval) {
+        let first = self.intervals.partition_point(|other| other.end < interval.start);
+        let mut last = first;
+        let mut pieces = Vec::new();
+        while last < self.intervals.len() && self.intervals[last].start <= interval.end {
+            let other = self.intervals[last];
+            if other.start < interval.start {
+                pieces.push(Interval::new(other.start, interval.start - 1));
+            }
+            if other.end > interval.end {
+                pieces.push(Interval::new(interval.end + 1, other.end));
+            }
+            last += 1;
+        }
+        self.intervals.splice(first..last, pieces);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        for &interval in other.intervals.iter() {
+            union.insert(interval);
+        }
+        return union;
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intersection = IntervalSet::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            if let Some(common) = self.intervals[i].intersection(other.intervals[j]) {
+                intersection.intervals.push(common);
+            }
+            // Move past the interval ending first, the other one may overlap
+            // the next ones
+            if self.intervals[i].end < other.intervals[j].end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        return intersection;
+    }
+
+    /// Integers of this set missing from `other`
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut difference = self.clone();
+        for &interval in other.intervals.iter() {
+            difference.remove(interval);
+        }
+        return difference;
+    }
+
+    /// Intervals of `within` left uncovered by the set, in increasing order
+    pub fn gaps(&self, within: Interval) -> impl Iterator<Item = Interval> + '_ {
+        let mut start = within.start;
+        let mut intervals = self.intervals.iter().skip_while(move |interval| interval.end < within.start);
+        return std::iter::from_fn(move || {
+            while start <= within.end {
+                match intervals.next() {
+                    Some(interval) if interval.start > start => {
+                        let gap = Interval::new(start, min(interval.start - 1, within.end));
+                        start = interval.end.saturating_add(1);
+                        return Some(gap);
+                    },
+                    Some(interval) => { start = interval.end.saturating_add(1); },
+                    None => {
+                        let gap = Interval::new(start, within.end);
+                        start = within.end.saturating_add(1);
+                        return Some(gap);
+                    }
+                }
+            }
+            return None;
+        });
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        Self { intervals: vec![interval] }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        return set;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        return intervals.iter().map(|&(start, end)| Interval::new(start, end)).collect();
+    }
+
+    #[test]
+    fn merges_and_splits() {
+        let mut intervals = set(&[(5, 8), (1, 2), (3, 4), (12, 15)]);
+        assert_eq!(intervals, set(&[(1, 8), (12, 15)]));
+        assert_eq!(intervals.len(), 12);
+        intervals.insert(Interval::new(7, 11));
+        assert_eq!(intervals, set(&[(1, 15)]));
+        intervals.remove(Interval::new(4, 6));
+        assert_eq!(intervals, set(&[(1, 3), (7, 15)]));
+        assert!(intervals.contains(3) && !intervals.contains(4) && intervals.contains(15));
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(a.union(&b), set(&[(0, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 4), (26, 30)]));
+        let gaps = a.gaps(Interval::new(-5, 40)).collect::<Vec<Interval>>();
+        assert_eq!(gaps, vec![Interval::new(-5, -1), Interval::new(11, 19), Interval::new(31, 40)]);
+        assert_eq!(a.gaps(Interval::new(2, 8)).count(), 0);
+    }
+}
@@ -0,0 +1,27 @@
+//! Code shared by every day of every year: input handling and parsing,
+//! answers of the challenges and their expected values, command line
+//! arguments of the runner, and the data structures, searches and letter
+//! recognition used by several days.
+
+pub mod cli;
+pub mod cycle;
+pub mod expected;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod ocr;
+pub mod params;
+pub mod parse;
+pub mod point;
+pub mod search;
+pub mod solution;
+pub mod visualize;
+
+pub use grid::Grid;
+pub use interval::{Interval, IntervalSet};
+pub use params::{Param, Params};
+pub use parse::ParseError;
+pub use point::{Direction, Point2, Point3};
+pub use solution::{Answer, SolveError, Solution, Solver};
+pub use visualize::Frame;
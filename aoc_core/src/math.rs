@@ -0,0 +1,129 @@
+/// Greatest common divisor, always non-negative
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    return a;
+}
+
+/// Least common multiple, always non-negative, `None` if it does not fit in
+/// an `i64`
+pub fn lcm(a: i64, b: i64) -> Option<i64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    return (a / gcd(a, b)).checked_mul(b).map(i64::abs);
+}
+
+/// Least common multiple of every value, 1 if there is none
+pub fn lcm_all(values: impl IntoIterator<Item = i64>) -> Option<i64> {
+    return values.into_iter().try_fold(1, lcm);
+}
+
+/// Greatest common divisor `g` of `a` and `b`, with `x` and `y` such that
+/// `a * x + b * y == g`
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        return (-old_r, -old_x, -old_y);
+    }
+    return (old_r, old_x, old_y);
+}
+
+/// Value between 0 and `modulus` (excluded) congruent to `a`
+pub fn modulo(a: i64, modulus: i64) -> i64 {
+    return a.rem_euclid(modulus);
+}
+
+/// `x` between 0 and `modulus` (excluded) such that `a * x` is congruent to
+/// 1, if `a` and `modulus` are coprime
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(modulo(a, modulus), modulus);
+    if g != 1 {
+        return None;
+    }
+    return Some(modulo(x, modulus));
+}
+
+/// `base` to the power of `exponent`, modulo `modulus`
+pub fn mod_pow(base: i64, exponent: u64, modulus: i64) -> i64 {
+    let modulus = modulus as i128;
+    let mut base = (base as i128).rem_euclid(modulus);
+    let mut exponent = exponent;
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    return result as i64;
+}
+
+/// Solve the system of congruences `x ≡ residue (mod modulus)` with the
+/// Chinese remainder theorem
+///
+/// Gives the smallest non-negative solution and the modulus of the
+/// solutions (the lcm of the moduli), or `None` if the congruences
+/// contradict each other or the lcm does not fit in an `i64`. The moduli do
+/// not have to be coprime.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut residue, mut modulus) = (0, 1);
+    for &(other_residue, other_modulus) in congruences.iter() {
+        let (g, x, _) = extended_gcd(modulus, other_modulus);
+        let difference = other_residue - residue;
+        if difference % g != 0 {
+            return None;
+        }
+        let combined = lcm(modulus, other_modulus)?;
+        // residue + modulus * k solves both, with k ≡ x * difference / g (mod other_modulus / g)
+        let step = (other_modulus / g) as i128;
+        let k = ((x as i128) * (difference / g) as i128).rem_euclid(step);
+        residue = ((residue as i128) + (modulus as i128) * k).rem_euclid(combined as i128) as i64;
+        modulus = combined;
+    }
+    return Some((residue, modulus));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divides() {
+        assert_eq!((gcd(12, -18), gcd(0, 5), gcd(0, 0)), (6, 5, 0));
+        assert_eq!((lcm(4, 6), lcm(-4, 6), lcm(0, 6)), (Some(12), Some(12), Some(0)));
+        assert_eq!(lcm_all([23, 19, 13, 17]), Some(96577));
+        assert_eq!(lcm(i64::MAX, 2), None);
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!((g, 240 * x + 46 * y), (2, 2));
+    }
+
+    #[test]
+    fn computes_modulo() {
+        assert_eq!(modulo(-7, 5), 3);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(i64::MAX - 1, 2, i64::MAX), 1);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+
+use crate::grid::Grid;
+use crate::point::Point2;
+
+/// Letters 6 pixels high, without their blank columns (most are 4 pixels
+/// wide)
+const FONT_6: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Letters 10 pixels high and 6 pixels wide
+const FONT_10: [(char, [&str; 10]); 15] = [
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// Group of lit pixels matching no letter of the fonts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownGlyph {
+    /// Column of the screen where the glyph starts
+    pub column: usize,
+    /// Rows of the glyph, lit pixels drawn as `#`
+    pub rows: Vec<String>,
+}
+
+impl fmt::Display for UnknownGlyph {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[OCR ERROR] Unknown glyph at column {}", self.column)?;
+        for row in self.rows.iter() {
+            write!(f, "\n{}", row)?;
+        }
+        return Ok(());
+    }
+}
+
+impl std::error::Error for UnknownGlyph {}
+
+impl UnknownGlyph {
+    /// Whether the glyph is as high as the letters of a font and no wider than
+    /// its widest letter, so likely a letter missing from the font rather
+    /// than a drawing that is not text at all
+    pub fn is_letter_sized(&self) -> bool {
+        let width = self.rows.first().map_or(0, |row| row.len());
+        return glyphs().any(|(_, glyph)| glyph.len() == self.rows.len() && glyph[0].len() >= width);
+    }
+}
+
+/// Every letter of both fonts
+fn glyphs() -> impl Iterator<Item = (char, &'static [&'static str])> {
+    let small = FONT_6.iter().map(|(c, glyph)| (*c, &glyph[..]));
+    let large = FONT_10.iter().map(|(c, glyph)| (*c, &glyph[..]));
+    return small.chain(large);
+}
+
+/// Letter drawn by the rows of a glyph, in either font
+fn letter(rows: &[String]) -> Option<char> {
+    return glyphs()
+        .find(|(_, glyph)| glyph.len() == rows.len() && glyph.iter().zip(rows).all(|(a, b)| *a == *b))
+        .map(|(c, _)| c);
+}
+
+/// Text written in capital letters on a screen, `lit` telling which pixels
+/// are on
+///
+/// The letters are the groups of columns holding lit pixels, the blank rows
+/// above and below them being left out, so they can be drawn anywhere on the
+/// screen as long as blank columns keep them apart.
+pub fn read<T, F>(screen: &Grid<T>, mut lit: F) -> Result<String, UnknownGlyph>
+where
+    F: FnMut(&T) -> bool,
+{
+    let pixels = screen.map(|pixel| lit(pixel));
+    let lit_rows: Vec<usize> = (0..pixels.height()).filter(|&y| pixels.row(y).contains(&true)).collect();
+    let (top, bottom) = match (lit_rows.first(), lit_rows.last()) {
+        (Some(&top), Some(&bottom)) => { (top, bottom) },
+        _ => { return Ok(String::new()); }
+    };
+    let is_blank = |x: usize| !pixels.column(x).any(|&pixel| pixel);
+
+    let mut text = String::new();
+    let mut x = 0;
+    while x < pixels.width() {
+        if is_blank(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < pixels.width() && !is_blank(x) {
+            x += 1;
+        }
+        let rows: Vec<String> = (top..=bottom)
+            .map(|y| (start..x).map(|x| if pixels[Point2::new(x, y)] { '#' } else { '.' }).collect())
+            .collect();
+        match letter(&rows) {
+            Some(c) => { text.push(c); },
+            None => { return Err(UnknownGlyph { column: start, rows }); }
+        }
+    }
+    return Ok(text);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Screen writing every letter of a font, a blank column apart
+    fn alphabet<const N: usize>(font: &[(char, [&str; N])]) -> (String, Grid<bool>) {
+        let letters = font.iter().map(|(c, _)| *c).collect();
+        let rows: Vec<String> = (0..N).map(|y| font.iter().map(|(_, glyph)| glyph[y]).collect::<Vec<&str>>().join(".")).collect();
+        return (letters, Grid::parse(&rows.join("\n"), |c| Ok(c == '#')).unwrap());
+    }
+
+    #[test]
+    fn reads_letters() {
+        let screen = Grid::parse(
+            "####.####.####..##..#..#...##..##..###..\n\
+             #.......#.#....#..#.#..#....#.#..#.#..#.\n\
+             ###....#..###..#....####....#.#..#.###..\n\
+             #.....#...#....#....#..#....#.####.#..#.\n\
+             #....#....#....#..#.#..#.#..#.#..#.#..#.\n\
+             ####.####.#.....##..#..#..##..#..#.###..",
+            Ok,
+        ).unwrap();
+        assert_eq!(read(&screen, |&pixel| pixel == '#'), Ok(String::from("EZFCHJAB")));
+
+        for (letters, screen) in [alphabet(&FONT_6), alphabet(&FONT_10)] {
+            assert_eq!(read(&screen, |&pixel| pixel), Ok(letters));
+        }
+        assert_eq!(read(&Grid::new(5, 3, false), |&pixel| pixel), Ok(String::new()));
+    }
+
+    #[test]
+    fn rejects_unknown_glyphs() {
+        let screen = Grid::parse("......\n.##.#.\n.#..#.\n......", |c| Ok(c == '#')).unwrap();
+        let error = read(&screen, |&pixel| pixel).unwrap_err();
+        assert_eq!(error, UnknownGlyph { column: 1, rows: vec![String::from("##"), String::from("#.")] });
+        assert_eq!(error.to_string(), "[OCR ERROR] Unknown glyph at column 1\n##\n#.");
+        assert!(!error.is_letter_sized());
+
+        // Letters without a blank column between them are too wide
+        let screen = Grid::parse(".##.#...#\n#..#.#.##\n#..##.#.#\n####.#..#\n#..##...#\n#..##...#", |c| Ok(c == '#')).unwrap();
+        let error = read(&screen, |&pixel| pixel).unwrap_err();
+        assert_eq!((error.column, error.rows[0].as_str()), (0, ".##.#...#"));
+        assert!(!error.is_letter_sized());
+        // An M, which is not part of the fonts
+        let screen = Grid::parse("#...#\n##.##\n#.#.#\n#...#\n#...#\n#...#", |c| Ok(c == '#')).unwrap();
+        assert!(read(&screen, |&pixel| pixel).unwrap_err().is_letter_sized());
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Named values given to the challenges of a day, by name
+pub type Overrides = HashMap<String, String>;
+
+/// Parameter of the challenges of a day (e.g. the number of rounds to
+/// simulate), declared by its `Solution`
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    pub help: &'static str,
+    /// Default value for each challenge
+    pub defaults: [&'static str; 2],
+    /// Checks that a value has the type expected by the solution
+    check: fn(&str) -> Result<(), String>,
+    /// Smallest value accepted, for integer parameters
+    min: Option<i64>,
+}
+
+fn check<T>(value: &str) -> Result<(), String>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    match value.parse::<T>() {
+        Ok(_) => { Ok(()) },
+        Err(e) => { Err(e.to_string()) },
+    }
+}
+
+impl Param {
+    /// Parameter of type `T` having the same default value for both challenges
+    pub fn new<T>(name: &'static str, help: &'static str, default: &'static str) -> Param
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        return Param::per_part::<T>(name, help, default, default);
+    }
+
+    /// Parameter of type `T` having a different default value for each
+    /// challenge
+    pub fn per_part<T>(name: &'static str, help: &'static str, part1: &'static str, part2: &'static str) -> Param
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        return Param { name, help, defaults: [part1, part2], check: check::<T>, min: None };
+    }
+
+    /// Reject the values of this integer parameter smaller than `min` (e.g. a
+    /// count that cannot be 0)
+    pub fn at_least(mut self, min: i64) -> Param {
+        self.min = Some(min);
+        return self;
+    }
+
+    /// Checks that a value suits the parameter
+    fn validate(&self, value: &str) -> Result<(), String> {
+        (self.check)(value)?;
+        if let Some(min) = self.min {
+            match value.parse::<i128>() {
+                Ok(number) if number < min as i128 => { return Err(format!("must be at least {}", min)); },
+                Ok(_) => {},
+                Err(e) => { return Err(e.to_string()); }
+            }
+        }
+        return Ok(());
+    }
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.defaults[0] == self.defaults[1] {
+            write!(f, "{}={}: {}", self.name, self.defaults[0], self.help)?;
+        } else {
+            write!(f, "{}={} (part 2: {}): {}", self.name, self.defaults[0], self.defaults[1], self.help)?;
+        }
+        if let Some(min) = self.min {
+            write!(f, " (at least {})", min)?;
+        }
+        return Ok(());
+    }
+}
+
+/// Values of the parameters of a challenge
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    values: HashMap<&'static str, String>,
+}
+
+impl Params {
+    /// Values of the declared parameters for a challenge: the defaults,
+    /// replaced by each layer of overrides in turn (e.g. the values recorded
+    /// for the input, then the ones given on the command line)
+    pub fn resolve(declared: &[Param], challenge_num: u8, layers: &[&Overrides]) -> Result<Params, String> {
+        let part = match challenge_num {
+            1 | 2 => { challenge_num as usize - 1 },
+            n => { panic!("[ERROR] Incorrect challenge number {}", n); }
+        };
+        let mut values = HashMap::new();
+        for param in declared.iter() {
+            values.insert(param.name, String::from(param.defaults[part]));
+        }
+
+        for overrides in layers.iter() {
+            for (name, value) in overrides.iter() {
+                let param = match declared.iter().find(|param| param.name == name) {
+                    Some(param) => { param },
+                    None => {
+                        let names = declared.iter().map(|param| param.name).collect::<Vec<&str>>();
+                        if names.is_empty() {
+                            return Err(format!("Unknown parameter '{}', this day takes none", name));
+                        }
+                        return Err(format!("Unknown parameter '{}', expected one of: {}", name, names.join(", ")));
+                    }
+                };
+                if let Err(e) = param.validate(value) {
+                    return Err(format!("Invalid value '{}' for parameter '{}': {}", value, name, e));
+                }
+                values.insert(param.name, value.clone());
+            }
+        }
+
+        return Ok(Params { values });
+    }
+
+    /// Value of a declared parameter
+    pub fn get<T>(&self, name: &str) -> T
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let value = match self.values.get(name) {
+            Some(value) => { value },
+            None => { panic!("[ERROR] Undeclared parameter '{}'", name); }
+        };
+        match value.parse::<T>() {
+            Ok(value) => { value },
+            Err(e) => { panic!("[ERROR] Invalid value '{}' for parameter '{}': {}", value, name, e); }
+        }
+    }
+}
+
+/// Parse a `name=value` command line argument
+pub fn parse_assignment(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((name, value)) => { Ok((String::from(name.trim()), String::from(value.trim()))) },
+        None => { Err(format!("Expected name=value, got '{}'", arg)) },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn declared() -> Vec<Param> {
+        return vec![
+            Param::per_part::<usize>("rounds", "Number of rounds", "20", "10000"),
+            Param::new::<i32>("row", "Row to look at", "2000000"),
+        ];
+    }
+
+    fn overrides(pairs: &[(&str, &str)]) -> Overrides {
+        return pairs.iter().map(|&(name, value)| (String::from(name), String::from(value))).collect();
+    }
+
+    #[test]
+    fn resolves_defaults_per_part() {
+        let params = Params::resolve(&declared(), 1, &[]).unwrap();
+        assert_eq!((params.get::<usize>("rounds"), params.get::<i32>("row")), (20, 2000000));
+        let params = Params::resolve(&declared(), 2, &[]).unwrap();
+        assert_eq!((params.get::<usize>("rounds"), params.get::<i32>("row")), (10000, 2000000));
+        assert_eq!(declared()[0].to_string(), "rounds=20 (part 2: 10000): Number of rounds");
+        assert_eq!(declared()[1].to_string(), "row=2000000: Row to look at");
+    }
+
+    #[test]
+    fn applies_layers_in_order() {
+        let answers = overrides(&[("rounds", "5"), ("row", "10")]);
+        let command_line = overrides(&[("rounds", "7")]);
+        let params = Params::resolve(&declared(), 2, &[&answers, &command_line]).unwrap();
+        assert_eq!((params.get::<usize>("rounds"), params.get::<i32>("row")), (7, 10));
+        let params = Params::resolve(&declared(), 2, &[&command_line, &answers]).unwrap();
+        assert_eq!(params.get::<usize>("rounds"), 5);
+    }
+
+    #[test]
+    fn rejects_unknown_or_invalid_values() {
+        let error = Params::resolve(&declared(), 1, &[&overrides(&[("max", "20")])]).unwrap_err();
+        assert_eq!(error, "Unknown parameter 'max', expected one of: rounds, row");
+        let error = Params::resolve(&[], 1, &[&overrides(&[("max", "20")])]).unwrap_err();
+        assert_eq!(error, "Unknown parameter 'max', this day takes none");
+        let error = Params::resolve(&declared(), 1, &[&overrides(&[("rounds", "-1")])]).unwrap_err();
+        assert!(error.starts_with("Invalid value '-1' for parameter 'rounds'"));
+
+        let knots = [Param::per_part::<usize>("knots", "Number of knots", "2", "10").at_least(1)];
+        assert_eq!(knots[0].to_string(), "knots=2 (part 2: 10): Number of knots (at least 1)");
+        let error = Params::resolve(&knots, 1, &[&overrides(&[("knots", "0")])]).unwrap_err();
+        assert_eq!(error, "Invalid value '0' for parameter 'knots': must be at least 1");
+        assert!(Params::resolve(&knots, 1, &[&overrides(&[("knots", "1")])]).is_ok());
+    }
+
+    #[test]
+    fn parses_assignments() {
+        assert_eq!(parse_assignment(" rounds = 5000"), Ok((String::from("rounds"), String::from("5000"))));
+        assert_eq!(parse_assignment("x=a=b"), Ok((String::from("x"), String::from("a=b"))));
+        assert!(parse_assignment("rounds").is_err());
+    }
+}
@@ -0,0 +1,327 @@
+use std::char::ParseCharError;
+use std::convert::Infallible;
+use std::fmt;
+use std::num::{ParseFloatError, ParseIntError};
+use std::str::{FromStr, ParseBoolError};
+
+use crate::input;
+
+/// Position of a parse error in the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column number (in characters), starting at 1
+    pub column: usize,
+    /// Whole line containing the error
+    pub snippet: String,
+    /// Number of characters of the line to highlight
+    pub width: usize,
+}
+
+/// Error raised by the parser of a day
+///
+/// Parsers only report the slice of the input they could not make sense of,
+/// the error is then located in the whole input (see `ParseError::locate`)
+/// and the file name is added by the runner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub file: Option<String>,
+    pub location: Option<Location>,
+    /// Address and length of the offending slice
+    span: (usize, usize),
+}
+
+impl ParseError {
+    /// Error on `part`, which must be a slice of the parsed input to be located
+    pub fn new(part: &str, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            file: None,
+            location: None,
+            span: (part.as_ptr() as usize, part.len()),
+        }
+    }
+
+    /// Find the offending slice in the input it was taken from
+    ///
+    /// The location stays unknown if the slice does not belong to the input.
+    pub fn locate(&mut self, input: &str) {
+        let base = input.as_ptr() as usize;
+        let (start, len) = self.span;
+        if self.location.is_some() || start < base || start + len > base + input.len() {
+            return;
+        }
+        let offset = start - base;
+        let line_start = match input[..offset].rfind('\n') {
+            Some(index) => { index + 1 },
+            None => { 0 },
+        };
+        let line_end = match input[offset..].find('\n') {
+            Some(index) => { offset + index },
+            None => { input.len() },
+        };
+        let snippet = input[line_start..line_end].trim_end_matches('\r');
+        let highlighted = &input[offset..(offset + len).min(line_end)];
+
+        self.location = Some(Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: String::from(snippet),
+            width: highlighted.chars().count().max(1),
+        });
+    }
+
+    pub fn with_file(mut self, file: &str) -> Self {
+        self.file = Some(String::from(file));
+        return self;
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[PARSE ERROR] {}", self.message)?;
+        let file = self.file.as_deref().unwrap_or("<input>");
+        match &self.location {
+            Some(location) => {
+                let gutter = " ".repeat(location.line.to_string().len());
+                write!(f, "\n{}--> {}:{}:{}", gutter, file, location.line, location.column)?;
+                write!(f, "\n{} |", gutter)?;
+                write!(f, "\n{} | {}", location.line, location.snippet)?;
+                write!(
+                    f,
+                    "\n{} | {}{}",
+                    gutter,
+                    " ".repeat(location.column - 1),
+                    "^".repeat(location.width)
+                )
+            },
+            None => { write!(f, "\n --> {}", file) },
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse a number, the error points to the whole text
+pub fn number<T>(s: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    match s.parse::<T>() {
+        Ok(value) => { Ok(value) },
+        Err(e) => { Err(ParseError::new(s, format!("Invalid number '{}': {}", s, e))) },
+    }
+}
+
+/// Parse every character of the text as a decimal digit
+pub fn digits(s: &str) -> Result<Vec<u32>, ParseError> {
+    let mut digits = Vec::new();
+    for (index, c) in s.char_indices() {
+        match c.to_digit(10) {
+            Some(digit) => { digits.push(digit); },
+            None => { return Err(ParseError::new(&s[index..index + c.len_utf8()], "Expected a digit")); }
+        }
+    }
+    return Ok(digits);
+}
+
+/// Remove an expected prefix, the error points to the text missing it
+pub fn prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    match s.strip_prefix(prefix) {
+        Some(rest) => { Ok(rest) },
+        None => { Err(ParseError::new(s, format!("Expected '{}'", prefix))) },
+    }
+}
+
+/// Split the text around the first occurrence of a separator
+pub fn split_once<'a>(s: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    match s.split_once(separator) {
+        Some(parts) => { Ok(parts) },
+        None => { Err(ParseError::new(s, format!("Expected '{}'", separator))) },
+    }
+}
+
+/// Parse the numbers of a list, ignoring the blank space around them (e.g.
+/// column aligned numbers separated by several spaces)
+pub fn numbers<T>(s: &str, separator: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    return s.split(separator)
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(number::<T>)
+        .collect();
+}
+
+/// Parse each block of lines separated by blank lines
+pub fn blocks<T, F>(input: &str, block: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    return input::paragraphs(input).into_iter().map(block).collect();
+}
+
+/// Split the text along a pattern whose `{}` placeholders stand for fields,
+/// the rest of the pattern having to match exactly
+///
+/// Each field ends at the first occurrence of the text following it in the
+/// pattern, the last one taking the rest of the text if the pattern ends
+/// with a field. The error points to the text failing to match.
+pub fn scan<'a>(s: &'a str, pattern: &str) -> Result<Vec<&'a str>, ParseError> {
+    let mut literals = pattern.split("{}");
+    let mut rest = prefix(s, literals.next().unwrap_or(""))?;
+    let literals = literals.collect::<Vec<&str>>();
+    let mut fields = Vec::new();
+    for (index, literal) in literals.iter().enumerate() {
+        if !literal.is_empty() {
+            let (field, tail) = split_once(rest, literal)?;
+            fields.push(field);
+            rest = tail;
+        } else if index + 1 == literals.len() {
+            fields.push(rest);
+            rest = &rest[rest.len()..];
+        } else {
+            panic!("[ERROR] Fields of the pattern '{}' must be separated by some text", pattern);
+        }
+    }
+    if !rest.is_empty() {
+        return Err(ParseError::new(rest, format!("Unexpected '{}' after '{}'", rest, pattern)));
+    }
+    return Ok(fields);
+}
+
+/// Errors of the `FromStr` implementations of the types parsed by `field`
+pub trait FieldError {
+    /// Error located on the field that failed to parse
+    fn locate_on(self, field: &str) -> ParseError;
+}
+
+/// Located already, by the parser of the field
+impl FieldError for ParseError {
+    fn locate_on(self, _field: &str) -> ParseError {
+        return self;
+    }
+}
+
+/// Errors of the parsers of the standard types
+macro_rules! impl_field_error {
+    ($($t:ty),*) => {
+        $(
+            impl FieldError for $t {
+                fn locate_on(self, field: &str) -> ParseError {
+                    return ParseError::new(field, format!("Invalid value '{}': {}", field, self));
+                }
+            }
+        )*
+    };
+}
+
+impl_field_error!(ParseIntError, ParseFloatError, ParseBoolError, ParseCharError, Infallible);
+
+/// Parse a field matched by `scan`, the error points to the field
+pub fn field<T>(s: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: FieldError,
+{
+    match s.parse::<T>() {
+        Ok(value) => { Ok(value) },
+        Err(e) => { Err(e.locate_on(s)) },
+    }
+}
+
+/// Match a text against a pattern and parse its `{}` fields as the given
+/// types (see `parse::scan`)
+///
+/// `scan!(line, "move {} from {} to {}", usize, usize, usize)` gives a
+/// `Result<(usize, usize, usize), ParseError>`, a single type gives its value
+/// alone. Text fields can be parsed as `String`.
+#[macro_export]
+macro_rules! scan {
+    ($s:expr, $pattern:expr, $t:ty $(,)?) => {
+        $crate::parse::scan($s, $pattern).and_then(|fields| -> Result<$t, $crate::parse::ParseError> {
+            if fields.len() != 1 {
+                panic!("[ERROR] Pattern '{}' has {} fields, 1 type given", $pattern, fields.len());
+            }
+            return $crate::parse::field::<$t>(fields[0]);
+        })
+    };
+    ($s:expr, $pattern:expr, $($t:ty),+ $(,)?) => {
+        $crate::parse::scan($s, $pattern).and_then(|fields| -> Result<($($t,)+), $crate::parse::ParseError> {
+            let types = [$(stringify!($t)),+].len();
+            if fields.len() != types {
+                panic!("[ERROR] Pattern '{}' has {} fields, {} types given", $pattern, fields.len(), types);
+            }
+            let mut fields = fields.into_iter();
+            return Ok(($($crate::parse::field::<$t>(fields.next().unwrap())?,)+));
+        })
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point2;
+
+    #[test]
+    fn locates_errors() {
+        let input = "12\nab cd\n34";
+        let mut error = ParseError::new(&input[6..8], "cd is not a number");
+        error.locate(input);
+        let location = error.location.clone().unwrap();
+        assert_eq!((location.line, location.column, location.snippet.as_str(), location.width), (2, 4, "ab cd", 2));
+        assert_eq!(
+            error.with_file("input.txt").to_string(),
+            "[PARSE ERROR] cd is not a number\n --> input.txt:2:4\n  |\n2 | ab cd\n  |    ^^"
+        );
+
+        let input = "1\r\nbad\r\n3";
+        let mut error = ParseError::new(&input[3..6], "bad is not a number");
+        error.locate(input);
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column, location.snippet.as_str(), location.width), (2, 1, "bad", 3));
+
+        let mut error = ParseError::new("elsewhere", "not from the input");
+        error.locate(input);
+        assert_eq!(error.location, None);
+        assert_eq!(error.to_string(), "[PARSE ERROR] not from the input\n --> <input>");
+    }
+
+    #[test]
+    fn scans_patterns() {
+        let line = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+        let fields = scan!(line, "Sensor at x={}, y={}: closest beacon is at x={}, y={}", i32, i32, i32, i32);
+        assert_eq!(fields, Ok((2, 18, -2, 15)));
+        let beacon = scan!(line, "Sensor at {}: closest beacon is at {}", Point2<i32>, Point2<i32>).unwrap().1;
+        assert_eq!(beacon, Point2::new(-2, 15));
+        assert_eq!(scan!("root: pppw + sjmn", "{}: {} {} {}", String, String, char, String).unwrap().2, '+');
+        assert_eq!(scan!("move 1 from 2 to 1", "move {} from {} to {}", usize, usize, usize), Ok((1, 2, 1)));
+        assert_eq!(scan!("Monkey 3:", "Monkey {}:", usize), Ok(3));
+    }
+
+    #[test]
+    fn locates_scan_errors() {
+        let input = "move 1 from 2 to 1\nmove 3 from x to 1";
+        let line = input.lines().nth(1).unwrap();
+        let mut error = scan!(line, "move {} from {} to {}", usize, usize, usize).unwrap_err();
+        error.locate(input);
+        assert_eq!(error.location.map(|location| (location.line, location.column)), Some((2, 13)));
+
+        assert_eq!(scan!("move 1 to 2", "move {} from {} to {}", usize, usize, usize).unwrap_err().message, "Expected ' from '");
+        assert!(scan!("Monkey 3: ", "Monkey {}:", usize).is_err());
+    }
+
+    #[test]
+    fn parses_lists() {
+        assert_eq!(numbers::<i32>("22 13  17 11  0", " "), Ok(vec![22, 13, 17, 11, 0]));
+        assert_eq!(numbers::<i64>("79, 98", ","), Ok(vec![79, 98]));
+        assert!(numbers::<i64>("79, x", ",").is_err());
+        let sums = blocks("1\n2\n\n3", |block| block.lines().map(number::<i32>).sum());
+        assert_eq!(sums, Ok(vec![3, 3]));
+    }
+}
@@ -0,0 +1,387 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+
+/// Position or offset on a plane
+///
+/// The y axis points down, as the rows of a map read from the input.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Position or offset in space
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+/// Component-wise operators, and multiplication by a scalar
+macro_rules! impl_operators {
+    ($point:ident, $($axis:ident),+) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                Self { $($axis: self.$axis * factor),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$axis += other.$axis;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$axis -= other.$axis;)+
+            }
+        }
+    };
+}
+
+impl_operators!(Point2, x, y);
+impl_operators!(Point3, x, y, z);
+
+/// Distances, for every integer type
+macro_rules! impl_distances {
+    ($($t:ty),*) => {
+        $(
+            impl Point2<$t> {
+                /// Number of orthogonal steps between the points
+                pub fn manhattan(self, other: Self) -> $t {
+                    return (self.x.abs_diff(other.x) + self.y.abs_diff(other.y)) as $t;
+                }
+
+                /// Number of orthogonal or diagonal steps between the points
+                pub fn chebyshev(self, other: Self) -> $t {
+                    return self.x.abs_diff(other.x).max(self.y.abs_diff(other.y)) as $t;
+                }
+            }
+
+            impl Point3<$t> {
+                /// Number of orthogonal steps between the points
+                pub fn manhattan(self, other: Self) -> $t {
+                    return (self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)) as $t;
+                }
+
+                /// Number of orthogonal or diagonal steps between the points
+                pub fn chebyshev(self, other: Self) -> $t {
+                    return self.x.abs_diff(other.x).max(self.y.abs_diff(other.y)).max(self.z.abs_diff(other.z)) as $t;
+                }
+            }
+        )*
+    };
+}
+
+impl_distances!(i32, i64, isize, u32, u64, usize);
+
+/// Offsets of the directions, for every signed integer type
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Point2<$t> {
+                /// Offset of at most one step on each axis, towards the point
+                pub fn signum(self) -> Self {
+                    return Self::new(self.x.signum(), self.y.signum());
+                }
+
+                /// Point one step away in a direction
+                pub fn moved(self, direction: Direction) -> Self {
+                    let offset = direction.offset();
+                    return Self::new(self.x + offset.x as $t, self.y + offset.y as $t);
+                }
+            }
+
+            impl Point3<$t> {
+                /// Offset of at most one step on each axis, towards the point
+                pub fn signum(self) -> Self {
+                    return Self::new(self.x.signum(), self.y.signum(), self.z.signum());
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i32, i64, isize);
+
+impl Point2<usize> {
+    /// Point moved of a signed offset, unless it would go below zero
+    pub fn checked_add_signed(self, offset: Point2<isize>) -> Option<Self> {
+        return Some(Self::new(self.x.checked_add_signed(offset.x)?, self.y.checked_add_signed(offset.y)?));
+    }
+}
+
+impl Point3<usize> {
+    /// Point moved of a signed offset, unless it would go below zero
+    pub fn checked_add_signed(self, offset: Point3<isize>) -> Option<Self> {
+        return Some(Self::new(
+            self.x.checked_add_signed(offset.x)?,
+            self.y.checked_add_signed(offset.y)?,
+            self.z.checked_add_signed(offset.z)?,
+        ));
+    }
+}
+
+impl Point3<isize> {
+    /// Offsets of the 6 faces of a cube
+    pub const FACES: [Self; 6] = [
+        Self::new(-1, 0, 0), Self::new(1, 0, 0),
+        Self::new(0, -1, 0), Self::new(0, 1, 0),
+        Self::new(0, 0, -1), Self::new(0, 0, 1),
+    ];
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// Parse the comma separated coordinates of a point, each one optionally
+/// named (`3,4` or `x=3, y=4`)
+fn coordinates<T, const N: usize>(s: &str, axes: [&str; N]) -> Result<[T; N], ParseError>
+where
+    T: FromStr + Default + Copy,
+    T::Err: fmt::Display,
+{
+    let parts = s.split(',').collect::<Vec<&str>>();
+    if parts.len() != N {
+        return Err(ParseError::new(s, format!("Expected {} coordinates, got {}", N, parts.len())));
+    }
+    let mut values = [T::default(); N];
+    for (index, part) in parts.iter().enumerate() {
+        let part = part.trim();
+        let value = match part.split_once('=') {
+            Some((name, value)) if name.trim() == axes[index] => { value.trim() },
+            Some((name, _)) => { return Err(ParseError::new(name, format!("Expected coordinate {}", axes[index]))); },
+            None => { part },
+        };
+        values[index] = parse::number::<T>(value)?;
+    }
+    return Ok(values);
+}
+
+impl<T> FromStr for Point2<T>
+where
+    T: FromStr + Default + Copy,
+    T::Err: fmt::Display,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = coordinates(s, ["x", "y"])?;
+        Ok(Self { x, y })
+    }
+}
+
+impl<T> FromStr for Point3<T>
+where
+    T: FromStr + Default + Copy,
+    T::Err: fmt::Display,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = coordinates(s, ["x", "y", "z"])?;
+        Ok(Self { x, y, z })
+    }
+}
+
+/// Orthogonal direction on a plane, the y axis pointing down
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn offset(self) -> Point2<isize> {
+        match self {
+            Self::Up => { Point2::new(0, -1) },
+            Self::Right => { Point2::new(1, 0) },
+            Self::Down => { Point2::new(0, 1) },
+            Self::Left => { Point2::new(-1, 0) },
+        }
+    }
+
+    /// Direction a quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        return Self::ALL[(self as usize + 1) % 4];
+    }
+
+    /// Direction a quarter turn counterclockwise
+    pub fn turn_left(self) -> Self {
+        return Self::ALL[(self as usize + 3) % 4];
+    }
+
+    pub fn opposite(self) -> Self {
+        return Self::ALL[(self as usize + 2) % 4];
+    }
+
+    /// Direction of an `U`, `R`, `D` or `L` letter, or of a compass point
+    /// (`N`, `E`, `S` or `W`)
+    pub fn from_letter(letter: char) -> Option<Self> {
+        match letter {
+            'U' | 'N' | '^' => { Some(Self::Up) },
+            'R' | 'E' | '>' => { Some(Self::Right) },
+            'D' | 'S' | 'v' => { Some(Self::Down) },
+            'L' | 'W' | '<' => { Some(Self::Left) },
+            _ => { None },
+        }
+    }
+}
+
+/// Orthogonal or diagonal direction on a plane, the y axis pointing down
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    UpLeft,
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from up left
+    pub const ALL: [Self; 8] = [
+        Self::UpLeft, Self::Up, Self::UpRight, Self::Right,
+        Self::DownRight, Self::Down, Self::DownLeft, Self::Left,
+    ];
+
+    pub fn offset(self) -> Point2<isize> {
+        match self {
+            Self::UpLeft => { Point2::new(-1, -1) },
+            Self::Up => { Point2::new(0, -1) },
+            Self::UpRight => { Point2::new(1, -1) },
+            Self::Right => { Point2::new(1, 0) },
+            Self::DownRight => { Point2::new(1, 1) },
+            Self::Down => { Point2::new(0, 1) },
+            Self::DownLeft => { Point2::new(-1, 1) },
+            Self::Left => { Point2::new(-1, 0) },
+        }
+    }
+
+    /// Direction an eighth of a turn clockwise
+    pub fn turn_right(self) -> Self {
+        return Self::ALL[(self as usize + 1) % 8];
+    }
+
+    /// Direction an eighth of a turn counterclockwise
+    pub fn turn_left(self) -> Self {
+        return Self::ALL[(self as usize + 7) % 8];
+    }
+
+    pub fn opposite(self) -> Self {
+        return Self::ALL[(self as usize + 4) % 8];
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => { Self::Up },
+            Direction::Right => { Self::Right },
+            Direction::Down => { Self::Down },
+            Direction::Left => { Self::Left },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_both_formats() {
+        assert_eq!("3,-4".parse::<Point2<i32>>(), Ok(Point2::new(3, -4)));
+        assert_eq!("x=3, y=-4".parse::<Point2<i32>>(), Ok(Point2::new(3, -4)));
+        assert_eq!("1,2,3".parse::<Point3<usize>>(), Ok(Point3::new(1, 2, 3)));
+        assert_eq!("y=3, x=4".parse::<Point2<i32>>().unwrap_err().message, "Expected coordinate x");
+        assert!("1,2".parse::<Point3<usize>>().is_err());
+    }
+
+    #[test]
+    fn computes() {
+        let a = Point2::<i32>::new(1, 5);
+        let b = Point2::new(4, 1);
+        assert_eq!(a + b, Point2::new(5, 6));
+        assert_eq!((a - b) * 2, Point2::new(-6, 8));
+        assert_eq!((a - b).signum(), Point2::new(-1, 1));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point2::new(2usize, 0).checked_add_signed(Direction::Up.offset()), None);
+        assert_eq!(a.moved(Direction::Left), Point2::new(0, 5));
+    }
+
+    #[test]
+    fn rotates() {
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Right.opposite(), Direction::Left);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::from(Direction::Down).opposite(), Direction8::Up);
+    }
+}
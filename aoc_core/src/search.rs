@@ -0,0 +1,205 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Outcome of a search: the cheapest cost found for each reached state, the
+/// state it was reached from and the goal met, if any
+///
+/// The states are only known through the `neighbors` closure given to the
+/// search, so no graph is built beforehand.
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    costs: HashMap<S, u64>,
+    parents: HashMap<S, S>,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new() -> Self {
+        Self { costs: HashMap::new(), parents: HashMap::new(), goal: None }
+    }
+
+    /// First state reached satisfying the goal, the cheapest one
+    pub fn goal(&self) -> Option<&S> {
+        return self.goal.as_ref();
+    }
+
+    /// Cost of the goal reached
+    pub fn goal_cost(&self) -> Option<u64> {
+        return self.cost(self.goal.as_ref()?);
+    }
+
+    /// Cheapest cost found to reach `state`, exact for the states settled
+    /// before the search stopped
+    pub fn cost(&self, state: &S) -> Option<u64> {
+        return self.costs.get(state).copied();
+    }
+
+    /// Every reached state with its cost
+    pub fn costs(&self) -> &HashMap<S, u64> {
+        return &self.costs;
+    }
+
+    /// States from one of the starts to `state`, both included
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        if !self.costs.contains_key(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        return Some(path);
+    }
+
+    /// States from one of the starts to the goal reached
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        return self.path(self.goal.as_ref()?);
+    }
+}
+
+/// Breadth first search, every move costing 1
+///
+/// Every start is at cost 0. The search stops at the first state satisfying
+/// `is_goal`, or once every reachable state is visited.
+pub fn bfs<S, I, N, G>(starts: impl IntoIterator<Item = S>, mut neighbors: N, mut is_goal: G) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.costs.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        let cost = search.costs[&state] + 1;
+        for next in neighbors(&state) {
+            if let Entry::Vacant(entry) = search.costs.entry(next.clone()) {
+                entry.insert(cost);
+                search.parents.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    return search;
+}
+
+/// Dijkstra search, each neighbor coming with the cost of the move to it
+///
+/// Every start is at cost 0. The search stops at the first state satisfying
+/// `is_goal`, the cheapest one, or once every reachable state is settled.
+pub fn dijkstra<S, I, N, G>(starts: impl IntoIterator<Item = S>, neighbors: N, is_goal: G) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    return astar(starts, neighbors, |_| 0, is_goal);
+}
+
+/// A* search, exploring first the states whose cost plus `heuristic` is the
+/// lowest
+///
+/// The heuristic must never overestimate the remaining cost to the goal, nor
+/// decrease by more than the cost of a move, for the goal found to be the
+/// cheapest one.
+pub fn astar<S, I, N, H, G>(starts: impl IntoIterator<Item = S>, mut neighbors: N, mut heuristic: H, mut is_goal: G) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+    N: FnMut(&S) -> I,
+    H: FnMut(&S) -> u64,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search::new();
+    // The heap holds indices of `states`, so that states need no ordering
+    let mut states = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.costs.insert(start.clone(), 0);
+            heap.push(Reverse((heuristic(&start), 0, states.len())));
+            states.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let state = states[index].clone();
+        if cost > search.costs[&state] {
+            // Already reached by a cheaper path
+            continue;
+        }
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        for (next, step) in neighbors(&state) {
+            let next_cost = cost + step;
+            if search.costs.get(&next).is_some_and(|&known| known <= next_cost) {
+                continue;
+            }
+            search.costs.insert(next.clone(), next_cost);
+            search.parents.insert(next.clone(), state.clone());
+            heap.push(Reverse((next_cost + heuristic(&next), next_cost, states.len())));
+            states.push(next);
+        }
+    }
+    return search;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Pos};
+    use crate::point::Point2;
+
+    fn maze() -> Grid<char> {
+        return Grid::parse("..#.\n.##.\n....\n#..#", Ok).unwrap();
+    }
+
+    fn open_neighbors(grid: &Grid<char>, pos: &Pos) -> Vec<Pos> {
+        return grid.neighbors4(*pos).filter(|&neighbor| grid[neighbor] == '.').collect();
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        let grid = maze();
+        let (start, end) = (Point2::new(0, 0), Point2::new(3, 0));
+        let search = bfs([start], |pos| open_neighbors(&grid, pos), |&pos| pos == end);
+        assert_eq!(search.goal_cost(), Some(7));
+        let path = search.goal_path().unwrap();
+        assert_eq!((path.len(), path[0], path[7]), (8, start, end));
+
+        let weighted = |pos: &Pos| open_neighbors(&grid, pos).into_iter().map(|next| (next, 2)).collect::<Vec<(Pos, u64)>>();
+        assert_eq!(dijkstra([start], weighted, |&pos| pos == end).goal_cost(), Some(14));
+        let distance = |pos: &Pos| (pos.manhattan(end) * 2) as u64;
+        assert_eq!(astar([start], weighted, distance, |&pos| pos == end).goal_cost(), Some(14));
+    }
+
+    #[test]
+    fn searches_from_several_starts() {
+        let grid = maze();
+        let end = Point2::new(3, 0);
+        let search = bfs([Point2::new(0, 0), Point2::new(2, 3)], |pos| open_neighbors(&grid, pos), |&pos| pos == end);
+        assert_eq!(search.goal_cost(), Some(4));
+        assert_eq!(search.goal_path().unwrap()[0], Point2::new(2, 3));
+
+        let search = bfs([Point2::new(0, 0)], |pos| open_neighbors(&grid, pos), |_| false);
+        assert_eq!((search.goal(), search.costs().len()), (None, 11));
+        assert_eq!(search.path(&Point2::new(0, 3)), None);
+    }
+}
@@ -0,0 +1,217 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::input;
+use crate::params::{Param, Params};
+use crate::parse::ParseError;
+
+/// Result of a challenge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    /// Rows of a rendered screen, to be read by a human when it does not
+    /// show letters `aoc_core::ocr` can read
+    Screen(Vec<String>),
+    /// No answer could be found from the parsed input, for this reason (e.g.
+    /// letters `aoc_core::ocr` does not know), reported as an error by the
+    /// `Solver`
+    Failed(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Integer(value) => { write!(f, "{}", value) },
+            Self::Text(text) => { write!(f, "{}", text) },
+            Self::Screen(rows) => {
+                for row in rows.iter() {
+                    write!(f, "\n{}", row)?;
+                }
+                Ok(())
+            },
+            Self::Failed(reason) => { write!(f, "{}", reason) },
+        }
+    }
+}
+
+impl Answer {
+    /// Compare with an expected answer written by hand, surrounding
+    /// whitespace (e.g. line breaks around a screen) is not significant
+    pub fn matches(&self, expected: &str) -> bool {
+        return self.to_string().trim() == expected.trim();
+    }
+}
+
+/// Lossless conversions of the integer types used by the solutions
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    match i64::try_from(value) {
+                        Ok(value) => { Self::Integer(value) },
+                        Err(_) => { panic!("[ERROR] Answer {} does not fit in an i64", value); }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(String::from(text))
+    }
+}
+
+/// Both challenges of a day: the input is parsed once, then given to the
+/// challenge being solved along with the values of its parameters
+pub trait Solution {
+    /// Parsed input of the day
+    type Input;
+
+    /// Parameters of the challenges, with their default values
+    fn params(&self) -> Vec<Param> {
+        return Vec::new();
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(&self, input: &Self::Input, params: &Params) -> Answer;
+    fn part2(&self, input: &Self::Input, params: &Params) -> Answer;
+}
+
+/// Answer of a challenge along with the time spent on each step
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Why a challenge gave no answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// Reason given by an `Answer::Failed`, and the input file if known
+    Failed(String, Option<String>),
+}
+
+impl SolveError {
+    /// Name the input file in the error, to locate parse errors
+    pub fn with_file(self, file: &str) -> Self {
+        match self {
+            Self::Parse(e) => { Self::Parse(e.with_file(file)) },
+            Self::Failed(reason, _) => { Self::Failed(reason, Some(String::from(file))) },
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parse(e) => { write!(f, "{}", e) },
+            Self::Failed(reason, None) => { write!(f, "{}", reason) },
+            Self::Failed(reason, Some(file)) => { write!(f, "{}\n --> {}", reason, file) },
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// Type erased `Solution`, allowing the runner to handle every day the same way
+///
+/// The input is normalized (see `input::normalize`) before being parsed, and
+/// parse errors are located in it before being returned. Failed answers are
+/// returned as errors too.
+pub trait Solver {
+    fn params(&self) -> Vec<Param>;
+    fn solve(&self, challenge_num: u8, input: &str, params: &Params) -> Result<Answer, SolveError>;
+    fn solve_timed(&self, challenge_num: u8, input: &str, params: &Params) -> Result<Timed, SolveError>;
+}
+
+impl<S: Solution> Solver for S {
+    fn params(&self) -> Vec<Param> {
+        return Solution::params(self);
+    }
+
+    fn solve(&self, challenge_num: u8, input: &str, params: &Params) -> Result<Answer, SolveError> {
+        return self.solve_timed(challenge_num, input, params).map(|timed| timed.answer);
+    }
+
+    fn solve_timed(&self, challenge_num: u8, input: &str, params: &Params) -> Result<Timed, SolveError> {
+        let input = &input::normalize(input);
+        let start = Instant::now();
+        let parsed = match self.parse(input) {
+            Ok(parsed) => { parsed },
+            Err(mut e) => {
+                e.locate(input);
+                return Err(SolveError::Parse(e));
+            }
+        };
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        let answer = match challenge_num {
+            1 => { self.part1(&parsed, params) },
+            2 => { self.part2(&parsed, params) },
+            n => { panic!("[ERROR] Incorrect challenge number {}", n); }
+        };
+        let solve_time = start.elapsed();
+
+        if let Answer::Failed(reason) = answer {
+            return Err(SolveError::Failed(reason, None));
+        }
+        return Ok(Timed { answer, parse_time, solve_time });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Letters;
+
+    impl Solution for Letters {
+        type Input = String;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            if input.chars().all(|c| c.is_ascii_uppercase()) {
+                return Ok(String::from(input));
+            }
+            return Err(ParseError::new(input, "Expected capital letters"));
+        }
+
+        fn part1(&self, input: &Self::Input, _params: &Params) -> Answer {
+            return Answer::from(input.as_str());
+        }
+
+        fn part2(&self, _input: &Self::Input, _params: &Params) -> Answer {
+            return Answer::Failed(String::from("[OCR ERROR] Unknown glyph at column 0"));
+        }
+    }
+
+    #[test]
+    fn reports_failed_answers() {
+        let params = Params::default();
+        assert_eq!(Letters.solve(1, "ABC\n", &params), Ok(Answer::from("ABC")));
+        assert!(matches!(Letters.solve(1, "abc", &params), Err(SolveError::Parse(_))));
+        let error = Letters.solve(2, "ABC", &params).unwrap_err();
+        assert_eq!(error.to_string(), "[OCR ERROR] Unknown glyph at column 0");
+        assert_eq!(error.with_file("input.txt").to_string(), "[OCR ERROR] Unknown glyph at column 0\n --> input.txt");
+    }
+}
@@ -0,0 +1,322 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::grid::Grid;
+use crate::point::Point2;
+
+/// Colors of the cells, as the standard terminal colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+}
+
+impl Color {
+    /// ANSI escape code setting the color of the text
+    pub fn ansi(&self) -> &'static str {
+        match self {
+            Color::Red => { "\x1b[31m" },
+            Color::Green => { "\x1b[32m" },
+            Color::Yellow => { "\x1b[33m" },
+            Color::Blue => { "\x1b[34m" },
+            Color::Magenta => { "\x1b[35m" },
+            Color::Cyan => { "\x1b[36m" },
+            Color::White => { "\x1b[97m" },
+            Color::Gray => { "\x1b[90m" },
+        }
+    }
+
+    /// Red, green and blue components of the color, to draw it in images
+    pub fn rgb(&self) -> [u8; 3] {
+        match self {
+            Color::Red => { [205, 49, 49] },
+            Color::Green => { [13, 188, 121] },
+            Color::Yellow => { [229, 229, 16] },
+            Color::Blue => { [36, 114, 200] },
+            Color::Magenta => { [188, 63, 188] },
+            Color::Cyan => { [17, 168, 205] },
+            Color::White => { [229, 229, 229] },
+            Color::Gray => { [102, 102, 102] },
+        }
+    }
+}
+
+/// ANSI escape code restoring the color of the terminal
+pub const RESET: &str = "\x1b[0m";
+/// ANSI escape code clearing the terminal
+pub const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+/// Character drawn at a position of a frame, in the default color if none is
+/// given
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub symbol: char,
+    pub color: Option<Color>,
+}
+
+impl Cell {
+    pub fn colored(symbol: char, color: Color) -> Self {
+        Self { symbol, color: Some(color) }
+    }
+}
+
+impl From<char> for Cell {
+    fn from(symbol: char) -> Self {
+        Self { symbol, color: None }
+    }
+}
+
+/// Picture of the state of a simulation, made of the cells plotted on an
+/// unbounded plane
+///
+/// Only the smallest box holding every plotted cell is drawn, the cells left
+/// out inside it being blank.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Frame {
+    title: Option<String>,
+    cells: HashMap<Point2<i64>, Cell>,
+}
+
+impl Frame {
+    pub fn new() -> Self {
+        Self { title: None, cells: HashMap::new() }
+    }
+
+    /// Frame drawing a cell for each cell of the grid
+    pub fn from_grid<T, F>(grid: &Grid<T>, mut draw: F) -> Self
+    where
+        F: FnMut(&T) -> Cell,
+    {
+        let mut frame = Frame::new();
+        for (pos, value) in grid.iter() {
+            frame.plot(pos.x as i64, pos.y as i64, draw(value));
+        }
+        return frame;
+    }
+
+    /// Text shown above the frame (e.g. the step of the simulation)
+    pub fn titled(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        return self;
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        return self.title.as_deref();
+    }
+
+    /// Cell plotted at column `x` and row `y`, if any
+    pub fn get(&self, x: i64, y: i64) -> Option<Cell> {
+        return self.cells.get(&Point2::new(x, y)).copied();
+    }
+
+    /// Every plotted cell with its position, in no particular order
+    pub fn cells(&self) -> impl Iterator<Item = (Point2<i64>, Cell)> + '_ {
+        return self.cells.iter().map(|(&pos, &cell)| (pos, cell));
+    }
+
+    /// Draw a cell at column `x` and row `y`, replacing the one already there
+    pub fn plot(&mut self, x: i64, y: i64, cell: impl Into<Cell>) {
+        self.cells.insert(Point2::new(x, y), cell.into());
+    }
+
+    /// Top left and bottom right corners of the smallest box holding every
+    /// plotted cell
+    pub fn bounds(&self) -> Option<(Point2<i64>, Point2<i64>)> {
+        let mut positions = self.cells.keys();
+        let first = *positions.next()?;
+        return Some(positions.fold((first, first), |(min, max), pos| {
+            (Point2::new(min.x.min(pos.x), min.y.min(pos.y)), Point2::new(max.x.max(pos.x), max.y.max(pos.y)))
+        }));
+    }
+
+    /// Rows of the frame, in a border, colored with ANSI escape codes if
+    /// `ansi` is set
+    pub fn render(&self, ansi: bool) -> String {
+        let (min, max) = self.bounds().unwrap_or((Point2::new(0, 0), Point2::new(-1, -1)));
+        let border = "━".repeat((max.x - min.x + 1) as usize);
+        let mut lines = Vec::new();
+        if let Some(title) = &self.title {
+            lines.push(title.clone());
+        }
+        lines.push(format!("┏{}┓", border));
+        for y in min.y..=max.y {
+            let mut line = String::from("┃");
+            for x in min.x..=max.x {
+                match self.cells.get(&Point2::new(x, y)) {
+                    Some(Cell { symbol, color: Some(color) }) if ansi => {
+                        line.push_str(color.ansi());
+                        line.push(*symbol);
+                        line.push_str(RESET);
+                    },
+                    Some(cell) => { line.push(cell.symbol); },
+                    None => { line.push(' '); },
+                }
+            }
+            line.push('┃');
+            lines.push(line);
+        }
+        lines.push(format!("┗{}┛", border));
+        return lines.join("\n");
+    }
+}
+
+/// Destination of the frames emitted by a simulation
+pub trait Output {
+    fn show(&mut self, frame: &Frame) -> io::Result<()>;
+
+    /// Called once the simulation is over
+    fn finish(&mut self) -> io::Result<()> {
+        return Ok(());
+    }
+}
+
+/// Shows the frames on the standard output, at the pace asked for
+pub struct Terminal {
+    /// Maximum number of frames shown per second, no limit if 0
+    fps: f64,
+    /// Color the cells and clear the screen between frames, to be set when
+    /// the standard output is a terminal
+    ansi: bool,
+    last_frame: Option<Instant>,
+}
+
+impl Terminal {
+    pub fn new(fps: f64, ansi: bool) -> Self {
+        Self { fps, ansi, last_frame: None }
+    }
+}
+
+impl Output for Terminal {
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        if self.fps > 0.0 {
+            let interval = Duration::from_secs_f64(1.0 / self.fps);
+            if let Some(elapsed) = self.last_frame.map(|last| last.elapsed()) {
+                if elapsed < interval {
+                    thread::sleep(interval - elapsed);
+                }
+            }
+        }
+        self.last_frame = Some(Instant::now());
+
+        let mut stdout = io::stdout().lock();
+        if self.ansi {
+            writeln!(stdout, "{}{}", CLEAR_SCREEN, frame.render(true))?;
+            return stdout.flush();
+        }
+        return writeln!(stdout, "{}\n", frame.render(false));
+    }
+}
+
+/// Output of the frames of the challenge run by a thread, keeping one frame
+/// every `skip + 1`
+struct Visualizer {
+    output: Box<dyn Output>,
+    skip: usize,
+    count: usize,
+}
+
+thread_local! {
+    /// Visualizer of the challenge run by the thread, if any
+    static VISUALIZER: RefCell<Option<Visualizer>> = const { RefCell::new(None) };
+}
+
+/// Send the frames emitted from now on by the current thread to `output`,
+/// leaving out `skip` frames after each one sent
+pub fn enable(output: Box<dyn Output>, skip: usize) {
+    VISUALIZER.with(|visualizer| *visualizer.borrow_mut() = Some(Visualizer { output, skip, count: 0 }));
+}
+
+/// Stop sending the frames of the current thread, and let the output know
+/// the simulation is over
+pub fn finish() -> io::Result<()> {
+    match VISUALIZER.with(|visualizer| visualizer.borrow_mut().take()) {
+        Some(mut visualizer) => { return visualizer.output.finish(); },
+        None => { return Ok(()); }
+    }
+}
+
+pub fn is_enabled() -> bool {
+    return VISUALIZER.with(|visualizer| visualizer.borrow().is_some());
+}
+
+/// Send the frame drawn by `draw` if frames are shown, `draw` is not called
+/// otherwise (or for skipped frames) so that the simulations run at full
+/// speed
+pub fn frame<F>(draw: F)
+where
+    F: FnOnce() -> Frame,
+{
+    VISUALIZER.with(|visualizer| {
+        if let Some(visualizer) = visualizer.borrow_mut().as_mut() {
+            visualizer.count += 1;
+            if (visualizer.count - 1) % (visualizer.skip + 1) != 0 {
+                return;
+            }
+            if let Err(e) = visualizer.output.show(&draw()) {
+                panic!("[ERROR] Could not show a frame: {}", e);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn renders_plotted_area() {
+        let mut frame = Frame::new().titled("Step 1");
+        frame.plot(-1, 2, 'H');
+        frame.plot(1, 3, Cell::colored('T', Color::Green));
+        assert_eq!(frame.bounds(), Some((Point2::new(-1, 2), Point2::new(1, 3))));
+        assert_eq!(frame.render(false), "Step 1\n┏━━━┓\n┃H  ┃\n┃  T┃\n┗━━━┛");
+        assert!(frame.render(true).contains("\x1b[32mT\x1b[0m┃"));
+        assert_eq!(Frame::new().render(false), "┏┓\n┗┛");
+
+        let grid = Grid::parse("#.\n.#", |c| Ok(c == '#')).unwrap();
+        let frame = Frame::from_grid(&grid, |&rock| Cell::from(if rock { '#' } else { '.' }));
+        assert_eq!(frame.render(false), "┏━━┓\n┃#.┃\n┃.#┃\n┗━━┛");
+    }
+
+    #[test]
+    fn draws_only_when_enabled() {
+        let mut drawn = false;
+        frame(|| {
+            drawn = true;
+            Frame::new()
+        });
+        assert!(!drawn && !is_enabled());
+    }
+
+    /// Keeps the titles of the frames it is sent
+    struct Titles(Rc<RefCell<Vec<String>>>);
+
+    impl Output for Titles {
+        fn show(&mut self, frame: &Frame) -> io::Result<()> {
+            self.0.borrow_mut().push(String::from(frame.title().unwrap_or("")));
+            return Ok(());
+        }
+    }
+
+    #[test]
+    fn skips_frames() {
+        let titles = Rc::new(RefCell::new(Vec::new()));
+        enable(Box::new(Titles(titles.clone())), 2);
+        for step in 0..7 {
+            frame(|| Frame::new().titled(step.to_string()));
+        }
+        finish().unwrap();
+        assert_eq!(*titles.borrow(), vec!["0", "3", "6"]);
+        assert!(!is_enabled());
+    }
+}